@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::parse_fields;
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let inserts = parse_fields(input)?
+        .into_iter()
+        .filter(|x| !x.skip)
+        .map(|field| {
+            let ident = &field.ident;
+            let key = &field.key;
+            if field.flatten {
+                quote! {
+                    match ::knife_util::bean::AsValueTrait::as_value(&self.#ident)
+                        .map_err(|e| ::knife_util::bean::field_path_error(e, #key))?
+                    {
+                        ::knife_util::Value::Object(obj) => map.extend(obj),
+                        ::knife_util::Value::Null => {}
+                        v => {
+                            return Err(::knife_util::bean::field_path_error(
+                                ::knife_util::error::ERR_CONVERT.msg_detail(
+                                    format!("flatten字段转换结果[{:?}]不是Object类型", v).as_str(),
+                                ),
+                                #key,
+                            ))
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    map.insert(
+                        #key.to_string(),
+                        ::knife_util::bean::AsValueTrait::as_value(&self.#ident)
+                            .map_err(|e| ::knife_util::bean::field_path_error(e, #key))?,
+                    );
+                }
+            }
+        });
+    Ok(quote! {
+        impl #impl_generics ::knife_util::bean::AsValueTrait for #name #ty_generics #where_clause {
+            fn as_value(&self) -> ::knife_util::Result<::knife_util::Value> {
+                let mut map = ::std::collections::BTreeMap::new();
+                #(#inserts)*
+                ::knife_util::OK(::knife_util::Value::Object(map))
+            }
+        }
+    })
+}
@@ -0,0 +1,92 @@
+use syn::{Data, DeriveInput, Error, Fields, Ident, Lit, Meta, NestedMeta, Path, Result, Type};
+
+/// 字段缺失时的默认值处理方式
+pub(crate) enum FieldDefault {
+    /// 未指定默认值
+    None,
+    /// 使用Default::default()
+    Default,
+    /// 使用指定函数
+    Path(Path),
+}
+
+/// 从#[knife(...)]中解析出的字段信息
+pub(crate) struct FieldAttr {
+    pub ident: Ident,
+    pub ty: Type,
+    pub key: String,
+    pub default: FieldDefault,
+    pub skip: bool,
+    pub flatten: bool,
+}
+
+/// 解析结构体中所有命名字段的属性，仅支持包含命名字段的结构体
+pub(crate) fn parse_fields(input: &DeriveInput) -> Result<Vec<FieldAttr>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "knife派生宏仅支持包含命名字段的结构体",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "knife派生宏仅支持包含命名字段的结构体",
+            ))
+        }
+    };
+    let mut res = vec![];
+    for field in fields {
+        let ident = field.ident.clone().unwrap();
+        let mut attr = FieldAttr {
+            key: ident.to_string().trim_start_matches("r#").to_string(),
+            ident,
+            ty: field.ty.clone(),
+            default: FieldDefault::None,
+            skip: false,
+            flatten: false,
+        };
+        for item in field.attrs.iter().filter(|x| x.path.is_ident("knife")) {
+            let list = match item.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "需使用#[knife(...)]格式")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(p)) if p.is_ident("skip") => attr.skip = true,
+                    NestedMeta::Meta(Meta::Path(p)) if p.is_ident("flatten") => attr.flatten = true,
+                    NestedMeta::Meta(Meta::Path(p)) if p.is_ident("default") => {
+                        attr.default = FieldDefault::Default
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                        match nv.lit {
+                            Lit::Str(s) => attr.key = s.value(),
+                            lit => return Err(Error::new_spanned(lit, "rename需为字符串")),
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                        match nv.lit {
+                            Lit::Str(s) => attr.default = FieldDefault::Path(s.parse()?),
+                            lit => {
+                                return Err(Error::new_spanned(lit, "default需为函数路径字符串"))
+                            }
+                        }
+                    }
+                    other => return Err(Error::new_spanned(other, "不支持的knife属性")),
+                }
+            }
+        }
+        if attr.skip && attr.flatten {
+            return Err(Error::new_spanned(
+                &attr.ident,
+                "skip与flatten属性不能同时使用",
+            ));
+        }
+        res.push(attr);
+    }
+    Ok(res)
+}
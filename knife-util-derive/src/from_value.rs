@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::{parse_fields, FieldDefault};
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = parse_fields(input)?.into_iter().map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let key = &field.key;
+        let default = match &field.default {
+            FieldDefault::Path(path) => Some(quote! { #path() }),
+            FieldDefault::Default => Some(quote! { ::std::default::Default::default() }),
+            FieldDefault::None if field.skip => Some(quote! { ::std::default::Default::default() }),
+            FieldDefault::None => None,
+        };
+        let convert = quote! {
+            <#ty as ::knife_util::bean::FromValueTrait>::from_value(v)
+                .map_err(|e| ::knife_util::bean::field_path_error(e, #key))?
+        };
+        let expr = if field.skip {
+            default.unwrap()
+        } else if field.flatten {
+            quote! { <#ty as ::knife_util::bean::FromValueTrait>::from_value(value)? }
+        } else if let Some(default) = default {
+            quote! {
+                match obj.get(#key) {
+                    Some(v) if !v.is_null()? => #convert,
+                    _ => #default,
+                }
+            }
+        } else {
+            quote! {
+                match obj.get(#key) {
+                    Some(v) => #convert,
+                    None => {
+                        let v = &::knife_util::Value::Null;
+                        #convert
+                    }
+                }
+            }
+        };
+        quote! { #ident: #expr, }
+    });
+    Ok(quote! {
+        impl #impl_generics ::knife_util::bean::FromValueTrait for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from_value(value: &::knife_util::Value) -> ::knife_util::Result<Self> {
+                let obj = value.as_object()?;
+                ::knife_util::OK(Self {
+                    #(#fields)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,44 @@
+//! knife-util派生宏
+//!
+//! 为结构体生成AsValueTrait、FromValueTrait及MergeValueTrait的实现，
+//! 通过knife_util::bean重新导出，不建议直接依赖本包
+//!
+//! 支持的字段属性：
+//! - `#[knife(rename = "name")]`：指定字段在Value中对应的键名
+//! - `#[knife(default)]`、`#[knife(default = "path")]`：字段缺失或为Null时使用默认值
+//! - `#[knife(skip)]`：忽略该字段，转换为结构体时使用默认值
+//! - `#[knife(flatten)]`：将字段内容展开到上一层Object中
+mod as_value;
+mod attr;
+mod from_value;
+mod merge_value;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// 生成AsValueTrait实现
+#[proc_macro_derive(AsValue, attributes(knife))]
+pub fn derive_as_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    as_value::expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 生成FromValueTrait实现
+#[proc_macro_derive(FromValue, attributes(knife))]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_value::expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 生成MergeValueTrait实现
+#[proc_macro_derive(MergeValue, attributes(knife))]
+pub fn derive_merge_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    merge_value::expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
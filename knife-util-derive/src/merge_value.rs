@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::parse_fields;
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let merges = parse_fields(input)?
+        .into_iter()
+        .filter(|x| !x.skip)
+        .map(|field| {
+            let ident = &field.ident;
+            let key = &field.key;
+            if field.flatten {
+                quote! {
+                    ::knife_util::bean::MergeValueTrait::merge_value(&mut self.#ident, Some(target))?;
+                }
+            } else {
+                quote! {
+                    ::knife_util::bean::MergeValueTrait::merge_value(&mut self.#ident, obj.get(#key))
+                        .map_err(|e| ::knife_util::bean::field_path_error(e, #key))?;
+                }
+            }
+        });
+    Ok(quote! {
+        impl #impl_generics ::knife_util::bean::MergeValueTrait for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn merge_value(
+                &mut self,
+                target: ::std::option::Option<&::knife_util::Value>,
+            ) -> ::knife_util::Result<Self> {
+                if let Some(target) = target {
+                    if !target.is_null()? {
+                        let obj = target.as_object()?;
+                        #(#merges)*
+                    }
+                }
+                ::knife_util::OK(::std::clone::Clone::clone(self))
+            }
+        }
+    })
+}
@@ -1,6 +1,7 @@
 use std::{
     alloc::{alloc_zeroed, Layout},
     any::type_name,
+    cell::Cell,
     fmt::Display,
     mem::MaybeUninit,
 };
@@ -12,27 +13,33 @@ use std::{
 /// 存入与取出数据时类型需保持一致
 /// 需要注意的是，AnyValue被简化为了Send+Sync类型的，但存入的数据并不做检查
 /// 在多线程环境下的使用，其数据安全性由开发者自身确认
-#[derive(Clone, Copy)]
+///
+/// 存入及取出数据时仅需共享引用，各状态字段均采用Cell保存
+#[derive(Clone)]
 pub struct AnyValue {
     /// 用于存放实际对象
-    pointer: Option<*mut u8>,
+    pointer: Cell<Option<*mut u8>>,
 
     /// 数据类型，用于取出时进行检查
-    type_name: &'static str,
+    type_name: Cell<&'static str>,
 
     /// 数据是否已取出
-    is_taken: bool,
+    is_taken: Cell<bool>,
 }
 
 impl Display for AnyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("AnyValue").field(&self.type_name).finish()
+        f.debug_tuple("AnyValue")
+            .field(&self.type_name.get())
+            .finish()
     }
 }
 
 impl std::fmt::Debug for AnyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("AnyValue").field(&self.type_name).finish()
+        f.debug_tuple("AnyValue")
+            .field(&self.type_name.get())
+            .finish()
     }
 }
 
@@ -43,9 +50,9 @@ impl AnyValue {
     /// 初始化一个不包含对象指针的空对象，用于点位
     pub fn new_zero() -> Self {
         AnyValue {
-            pointer: None,
-            type_name: "",
-            is_taken: false,
+            pointer: Cell::new(None),
+            type_name: Cell::new(""),
+            is_taken: Cell::new(false),
         }
     }
     /// 存入数据，需指定数据类型V
@@ -64,7 +71,7 @@ impl AnyValue {
 
     /// 检查指针是否绑定数据
     pub fn is_empty(&self) -> bool {
-        self.pointer.is_none()
+        self.pointer.get().is_none()
     }
 
     /// 替换数据，需指定数据类型V
@@ -80,7 +87,6 @@ impl AnyValue {
     }
 
     /// 替换数据，需指定数据类型V
-    #[allow(clippy::cast_ref_to_mut)]
     pub fn replace_with_write<V, F>(&self, v: V, f: F)
     where
         F: Fn(*mut V, V),
@@ -88,12 +94,9 @@ impl AnyValue {
         let layout = Layout::new::<MaybeUninit<V>>();
         let pointer = unsafe { alloc_zeroed(layout) };
         f(pointer.cast::<V>(), v);
-        unsafe {
-            let ptr = &mut *(self as *const Self as *mut Self);
-            ptr.type_name = type_name::<V>();
-            ptr.pointer = Some(pointer);
-            ptr.is_taken = false;
-        }
+        self.type_name.set(type_name::<V>());
+        self.pointer.set(Some(pointer));
+        self.is_taken.set(false);
     }
 
     /// 取出可变数据引用，可采用继承类型的特征，不需要与原始类型完全一致
@@ -102,7 +105,7 @@ impl AnyValue {
         self.check_taken();
         self.check_type::<V>();
         self.check_none();
-        unsafe { &mut *(self.pointer.unwrap() as *const V as *mut V) }
+        unsafe { &mut *(self.pointer.get().unwrap() as *const V as *mut V) }
     }
 
     /// 取出数据引用，可采用继承类型的特征，不需要与原始类型完全一致
@@ -110,7 +113,7 @@ impl AnyValue {
         self.check_taken();
         self.check_type::<V>();
         self.check_none();
-        unsafe { &*(self.pointer.unwrap() as *const V as *mut V) }
+        unsafe { &*(self.pointer.get().unwrap() as *const V as *mut V) }
     }
 
     /// 取出数据，只能执行一次
@@ -130,14 +133,13 @@ impl AnyValue {
     }
 
     /// 取出数据，只能执行一次
-    #[allow(clippy::cast_ref_to_mut)]
     pub fn take_with_read<V, F>(&self, f: F) -> V
     where
         F: Fn(*const V) -> V,
     {
-        let ptr = self.pointer.unwrap() as *const V;
+        let ptr = self.pointer.get().unwrap() as *const V;
         let v = f(ptr);
-        unsafe { &mut *(self as *const AnyValue as *mut AnyValue) }.is_taken = true;
+        self.is_taken.set(true);
         v
     }
 
@@ -147,24 +149,28 @@ impl AnyValue {
     }
 
     fn check_type<V>(&self) {
-        if self.type_name.is_empty() {
+        if self.type_name.get().is_empty() {
             return;
         }
         let type_name = type_name::<V>();
-        if type_name != self.type_name {
-            panic!("类型不一致，期望:{},实际:{}", type_name, self.type_name);
+        if type_name != self.type_name.get() {
+            panic!(
+                "类型不一致，期望:{},实际:{}",
+                type_name,
+                self.type_name.get()
+            );
         }
     }
 
     fn check_taken(&self) {
-        if self.is_taken {
-            panic!("该值已取出:{}", self.type_name);
+        if self.is_taken.get() {
+            panic!("该值已取出:{}", self.type_name.get());
         }
     }
 
     fn check_none(&self) {
-        if self.pointer.is_none() {
-            panic!("数据为空:{}", self.type_name);
+        if self.pointer.get().is_none() {
+            panic!("数据为空:{}", self.type_name.get());
         }
     }
 }
@@ -1,8 +1,20 @@
-use crate::{Result, Value};
+use crate::{
+    error::{AppError, ERR_DATA, ERR_MERGE},
+    Result, Value, OK,
+};
+
+use super::MergeOptions;
 
 /// 支持对象转换为内置Value格式
 pub trait AsValueTrait {
     fn as_value(&self) -> Result<Value>;
+
+    /// 转换时还原由from_typed_value写入的类型标记，使数据经Json、Yaml传输后与原数据一致
+    ///
+    /// 默认与as_value相同
+    fn as_typed_value(&self) -> Result<Value> {
+        self.as_value()
+    }
 }
 
 /// 支持内置Value转换为指定对象
@@ -10,6 +22,17 @@ pub trait FromValueTrait {
     fn from_value(value: &Value) -> Result<Self>
     where
         Self: Sized;
+
+    /// 转换时为Binary、日期等无法直接表示的类型写入类型标记，可通过as_typed_value还原
+    ///
+    /// Json中写入为`{"$date": "2022-10-01"}`格式的单键Object，Binary采用base64编码，
+    /// Yaml中写入为`!date 2022-10-01`格式的Tag，默认与from_value相同
+    fn from_typed_value(value: &Value) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_value(value)
+    }
 }
 
 /// 支持两个相同的Object对象进行合并
@@ -17,6 +40,19 @@ pub trait MergeTrait {
     fn merge_self(&mut self, target: &Self) -> Result<Self>
     where
         Self: Sized;
+
+    /// 按指定的合并选项进行合并，可设置数组合并策略及null删除语义等
+    ///
+    /// 默认仅支持默认选项，此时与merge_self相同，其余选项返回ERR_MERGE异常
+    fn merge_with(&mut self, target: &Self, options: &MergeOptions) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        if *options != MergeOptions::default() {
+            return Err(ERR_MERGE.msg_detail("不支持指定的合并选项"));
+        }
+        self.merge_self(target)
+    }
 }
 
 /// 支持两个相同的Object对象进行合并
@@ -41,9 +77,252 @@ pub trait PointerTrait {
     fn p(&self, pointer: &str) -> Option<&Self::Context>;
 }
 
-pub(super) fn parse_index(s: &str) -> Option<usize> {
+/// 可修改的可遍历特征
+///
+/// 支持以/a/b/c/2的方式修改指定层级上的对象，路径规则与PointerTrait相同
+pub trait PointerMutTrait: PointerTrait {
+    /// 获取指定层级上对象的可变引用
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context>;
+
+    /// 设置指定层级上的对象，并返回原有对象
+    ///
+    /// 路径中缺失的层级会自动创建，下一层为数组下标或`-`时创建数组，否则创建Object，
+    /// 数组中可使用`-`或等于数组长度的下标在尾部追加数据，
+    /// 写入前会先校验整个路径，路径不合法时不会修改原有对象
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>>;
+
+    /// 删除指定层级上的对象，路径不存在时返回None
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>>;
+}
+
+pub(crate) fn parse_index(s: &str) -> Option<usize> {
     if s.starts_with('+') || (s.starts_with('0') && s.len() != 1) {
         return None;
     }
     s.parse().ok()
 }
+
+/// 按RFC6901规则转义路径中的特殊字符，采用~0代替~，采用~1代替/
+pub(crate) fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// 将server.port格式的键转换为/server/port格式的路径，以/开头的键视为路径
+pub(crate) fn key_to_pointer(key: &str) -> String {
+    if key.is_empty() || key.starts_with('/') {
+        return key.to_string();
+    }
+    key.split('.')
+        .map(|x| format!("/{}", escape_token(x)))
+        .collect()
+}
+
+/// 将RFC6901格式的路径解析为各层级的键
+pub(super) fn parse_pointer(pointer: &str) -> Result<Vec<String>> {
+    if pointer.is_empty() {
+        return OK(vec![]);
+    }
+    if !pointer.starts_with('/') {
+        return Err(ERR_DATA.msg_detail(format!("路径[{}]需以/开头", pointer).as_str()));
+    }
+    OK(pointer
+        .split('/')
+        .skip(1)
+        .map(|x| x.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+/// 检查下一层级是否需要创建为数组
+pub(super) fn is_array_token(s: &str) -> bool {
+    s == "-" || parse_index(s).is_some()
+}
+
+/// 解析数组中用于写入的下标，`-`表示数组尾部
+pub(crate) fn parse_insert_index(s: &str, len: usize) -> Result<usize> {
+    let index = if s == "-" { Some(len) } else { parse_index(s) };
+    match index {
+        Some(v) if v <= len => OK(v),
+        _ => Err(ERR_DATA.msg_detail(format!("数组下标[{}]不合法，数组长度为{}", s, len).as_str())),
+    }
+}
+
+/// 支持按路径修改的树形数据节点，用于统一各数据格式的PointerMutTrait实现
+pub(super) trait PointerNode: Sized {
+    fn new_array() -> Self;
+
+    fn new_object() -> Self;
+
+    /// 空节点，写入时会按下一层级的键转换为数组或Object
+    fn is_null(&self) -> bool;
+
+    fn is_object(&self) -> bool;
+
+    fn list(&self) -> Option<&Vec<Self>>;
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>>;
+
+    /// 获取Object中的数据，非Object时返回None
+    fn get_key(&self, key: &str) -> Option<&Self>;
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self>;
+
+    /// 在Object中写入数据，并返回原有数据，仅在节点为Object时调用
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self>;
+
+    fn remove_key(&mut self, key: &str) -> Option<Self>;
+}
+
+/// 按下一层级的键创建空的数组或Object
+fn new_container<T: PointerNode>(token: &str) -> T {
+    if is_array_token(token) {
+        T::new_array()
+    } else {
+        T::new_object()
+    }
+}
+
+fn not_container(pointer: &str) -> AppError {
+    ERR_DATA.msg_detail(format!("路径[{}]的上级节点不是数组或Object类型", pointer).as_str())
+}
+
+fn child<'a, T: PointerNode>(target: &'a T, token: &str) -> Option<&'a T> {
+    match target.list() {
+        Some(list) => parse_index(token).and_then(|i| list.get(i)),
+        None => target.get_key(token),
+    }
+}
+
+fn child_mut<'a, T: PointerNode>(target: &'a mut T, token: &str) -> Option<&'a mut T> {
+    if target.list().is_some() {
+        return parse_index(token).and_then(move |i| target.list_mut()?.get_mut(i));
+    }
+    target.get_key_mut(token)
+}
+
+/// 获取指定层级上对象的可变引用
+pub(super) fn pointer_get_mut<'a, T: PointerNode>(
+    root: &'a mut T,
+    pointer: &str,
+) -> Option<&'a mut T> {
+    parse_pointer(pointer)
+        .ok()?
+        .iter()
+        .try_fold(root, |target, token| child_mut(target, token))
+}
+
+/// 校验路径上已有的节点均可写入，缺失的节点按下一层级的键进行校验
+fn check_writable<T: PointerNode>(root: &T, tokens: &[String], pointer: &str) -> Result<()> {
+    let mut current = Some(root);
+    for token in tokens {
+        current = match current.filter(|x| !x.is_null()) {
+            None => {
+                if is_array_token(token) {
+                    parse_insert_index(token, 0)?;
+                }
+                None
+            }
+            Some(node) => match node.list() {
+                Some(list) => list.get(parse_insert_index(token, list.len())?),
+                None if node.is_object() => node.get_key(token),
+                None => return Err(not_container(pointer)),
+            },
+        };
+    }
+    OK(())
+}
+
+/// 空节点按下一层级的键转换为数组或Object
+fn prepare_container<T: PointerNode>(target: &mut T, token: &str) {
+    if target.is_null() {
+        *target = new_container(token);
+    }
+}
+
+fn child_or_insert<'a, T: PointerNode>(
+    target: &'a mut T,
+    token: &str,
+    next: &str,
+    pointer: &str,
+) -> Result<&'a mut T> {
+    prepare_container(target, token);
+    if target.list().is_some() {
+        let list = target.list_mut().ok_or_else(|| not_container(pointer))?;
+        let index = parse_insert_index(token, list.len())?;
+        if index == list.len() {
+            list.push(new_container(next));
+        }
+        return OK(&mut list[index]);
+    }
+    if !target.is_object() {
+        return Err(not_container(pointer));
+    }
+    if target.get_key(token).is_none() {
+        target.insert_key(token, new_container(next));
+    }
+    target
+        .get_key_mut(token)
+        .ok_or_else(|| not_container(pointer))
+}
+
+/// 设置指定层级上的对象，并返回原有对象
+pub(super) fn pointer_set<T: PointerNode>(
+    root: &mut T,
+    pointer: &str,
+    value: T,
+) -> Result<Option<T>> {
+    let tokens = parse_pointer(pointer)?;
+    let (last, parents) = match tokens.split_last() {
+        Some(v) => v,
+        None => return OK(Some(std::mem::replace(root, value))),
+    };
+    check_writable(root, &tokens, pointer)?;
+    let mut target = root;
+    for (i, token) in parents.iter().enumerate() {
+        target = child_or_insert(target, token, &tokens[i + 1], pointer)?;
+    }
+    prepare_container(target, last);
+    if let Some(list) = target.list_mut() {
+        let index = parse_insert_index(last, list.len())?;
+        if index == list.len() {
+            list.push(value);
+            return OK(None);
+        }
+        return OK(Some(std::mem::replace(&mut list[index], value)));
+    }
+    if !target.is_object() {
+        return Err(not_container(pointer));
+    }
+    OK(target.insert_key(last, value))
+}
+
+/// 删除指定层级上的对象，路径不存在时不会修改原有对象
+pub(super) fn pointer_remove<T: PointerNode>(root: &mut T, pointer: &str) -> Result<Option<T>> {
+    let tokens = parse_pointer(pointer)?;
+    let (last, parents) = match tokens.split_last() {
+        Some(v) => v,
+        None => return Err(ERR_DATA.msg_detail("不支持删除根节点")),
+    };
+    let exists = parents
+        .iter()
+        .try_fold(&*root, |target, token| child(target, token))
+        .and_then(|parent| child(parent, last))
+        .is_some();
+    if !exists {
+        return OK(None);
+    }
+    let parent = match parents
+        .iter()
+        .try_fold(root, |target, token| child_mut(target, token))
+    {
+        Some(v) => v,
+        None => return OK(None),
+    };
+    if let Some(list) = parent.list_mut() {
+        return OK(parse_index(last).map(|i| list.remove(i)));
+    }
+    OK(parent.remove_key(last))
+}
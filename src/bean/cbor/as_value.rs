@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
+use crate::{
+    bean::AsValueTrait,
+    date::{Date, DateTime},
+    error::ERR_CONVERT,
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
+
+use super::codec::{
+    TAG_DATETIME_EPOCH, TAG_DATETIME_STRING, TAG_DATE_EPOCH, TAG_DATE_STRING, TAG_DECIMAL_FRACTION,
+    TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM,
+};
+
+impl AsValueTrait for ciborium::value::Value {
+    fn as_value(&self) -> Result<Value> {
+        match self {
+            ciborium::value::Value::Null => OK(Value::Null),
+            ciborium::value::Value::Bool(v) => OK(Value::Bool(*v)),
+            ciborium::value::Value::Integer(v) => {
+                if let Ok(x) = i64::try_from(*v) {
+                    OK(Value::I64(x))
+                } else if let Ok(x) = u64::try_from(*v) {
+                    OK(Value::U64(x))
+                } else {
+                    Err(ERR_CONVERT.msg_detail("CBOR整数超出内置Value对象支持的范围"))
+                }
+            }
+            ciborium::value::Value::Float(v) => OK(Value::F64(*v)),
+            ciborium::value::Value::Text(v) => OK(Value::String(v.clone())),
+            ciborium::value::Value::Bytes(v) => OK(Value::Binary(v.clone())),
+            ciborium::value::Value::Tag(tag, v) => tag_as_value(*tag, v),
+            ciborium::value::Value::Array(v) => v
+                .iter()
+                .map(|x| x.as_value())
+                .collect_into_vec()
+                .map(Value::Array),
+            ciborium::value::Value::Map(o) => {
+                let mut map = BTreeMap::new();
+                for (k, v) in o {
+                    map.insert(key_as_string(k)?, v.as_value()?);
+                }
+                OK(Value::Object(map))
+            }
+            _ => Err(ERR_CONVERT.msg_detail("暂不支持的CBOR数据类型")),
+        }
+    }
+}
+
+/// 日期相关的标签转换为内置日期类型，带有时区的日期时间转换为UTC时间，
+/// 十进制小数标签转换为Decimal，其余标签忽略
+fn tag_as_value(tag: u64, value: &ciborium::value::Value) -> Result<Value> {
+    let res = match (tag, value) {
+        (TAG_DATETIME_STRING, ciborium::value::Value::Text(s)) => {
+            chrono::DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|x| DateTime::from_datetime(x.naive_utc()).map(Value::DateTime))
+        }
+        (TAG_DATETIME_EPOCH, ciborium::value::Value::Integer(v)) => i64::try_from(*v)
+            .ok()
+            .and_then(|x| from_timestamp(x, 0))
+            .map(|x| DateTime::from_datetime(x).map(Value::DateTime)),
+        (TAG_DATETIME_EPOCH, ciborium::value::Value::Float(v)) if v.is_finite() => {
+            from_timestamp(v.floor() as i64, ((v - v.floor()) * 1e9) as i64)
+                .map(|x| DateTime::from_datetime(x).map(Value::DateTime))
+        }
+        (TAG_DATE_STRING, ciborium::value::Value::Text(s)) => {
+            Date::parse_str(s).ok().map(|x| OK(Value::Date(x)))
+        }
+        (TAG_DATE_EPOCH, ciborium::value::Value::Integer(v)) => i64::try_from(*v)
+            .ok()
+            .and_then(|x| {
+                chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?
+                    .checked_add_signed(chrono::Duration::days(x))
+            })
+            .map(|x| Date::from_date(x).map(Value::Date)),
+        (TAG_DECIMAL_FRACTION, ciborium::value::Value::Array(arr)) => {
+            decimal_fraction(arr).map(|x| OK(Value::Decimal(x)))
+        }
+        _ => return value.as_value(),
+    };
+    res.ok_or_else(|| ERR_CONVERT.msg_detail(format!("CBOR标签[{}]中的数据不合法", tag).as_str()))?
+}
+
+/// 十进制小数标签，数据为[指数, 尾数]，指数的绝对值不能超过Decimal支持的最大精度
+fn decimal_fraction(arr: &[ciborium::value::Value]) -> Option<Decimal> {
+    let (exponent, mantissa) = match arr {
+        [ciborium::value::Value::Integer(e), m] => (i64::try_from(*e).ok()?, bignum(m)?),
+        _ => return None,
+    };
+    let scale = u32::try_from(exponent.unsigned_abs())
+        .ok()
+        .filter(|x| *x <= Decimal::MAX_SCALE)?;
+    if exponent <= 0 {
+        Decimal::try_from_i128_with_scale(mantissa, scale).ok()
+    } else {
+        let value = mantissa.checked_mul(10i128.checked_pow(scale)?)?;
+        Decimal::try_from_i128_with_scale(value, 0).ok()
+    }
+}
+
+/// 读取整数或大整数标签中的数值，超出i128范围时返回None
+fn bignum(value: &ciborium::value::Value) -> Option<i128> {
+    let (tag, bytes) = match value {
+        ciborium::value::Value::Integer(v) => return Some(i128::from(*v)),
+        ciborium::value::Value::Tag(tag, v) => match v.as_ref() {
+            ciborium::value::Value::Bytes(bytes) if bytes.len() <= 15 => (*tag, bytes),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let n = bytes.iter().fold(0i128, |acc, x| (acc << 8) | *x as i128);
+    match tag {
+        TAG_POSITIVE_BIGNUM => Some(n),
+        TAG_NEGATIVE_BIGNUM => Some(-1 - n),
+        _ => None,
+    }
+}
+
+fn from_timestamp(secs: i64, nanos: i64) -> Option<chrono::NaiveDateTime> {
+    if secs.checked_abs()? > i64::MAX / 1000 {
+        return None;
+    }
+    chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(chrono::Duration::seconds(secs))?
+        .checked_add_signed(chrono::Duration::nanoseconds(nanos))
+}
+
+fn key_as_string(key: &ciborium::value::Value) -> Result<String> {
+    match key {
+        ciborium::value::Value::Text(v) => OK(v.clone()),
+        ciborium::value::Value::Integer(v) => OK(i128::from(*v).to_string()),
+        ciborium::value::Value::Bool(v) => OK(v.to_string()),
+        _ => Err(ERR_CONVERT.msg_detail("CBOR Map的键需为字符串、整数或布尔类型")),
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    bean::{AsValueTrait, FromValueTrait},
+    error::{ERR_DESERIALIZE, ERR_SERIALIZE},
+    Result, Value, OK,
+};
+
+/// RFC3339格式的日期时间标签
+pub(super) const TAG_DATETIME_STRING: u64 = 0;
+/// 以秒为单位的时间戳标签
+pub(super) const TAG_DATETIME_EPOCH: u64 = 1;
+/// 正大整数标签
+pub(super) const TAG_POSITIVE_BIGNUM: u64 = 2;
+/// 负大整数标签
+pub(super) const TAG_NEGATIVE_BIGNUM: u64 = 3;
+/// 十进制小数标签，数据为指数及尾数组成的数组，参考[RFC8949](https://tools.ietf.org/html/rfc8949)
+pub(super) const TAG_DECIMAL_FRACTION: u64 = 4;
+/// 以天为单位的日期标签，参考[RFC8943](https://tools.ietf.org/html/rfc8943)
+pub(super) const TAG_DATE_EPOCH: u64 = 100;
+/// RFC3339 full-date格式的日期标签，参考[RFC8943](https://tools.ietf.org/html/rfc8943)
+pub(super) const TAG_DATE_STRING: u64 = 1004;
+
+/// 将内置Value对象编码为CBOR格式
+///
+/// Date编码为标签1004，DateTime视为UTC时间并编码为标签0，Decimal编码为标签4，Binary编码为字节串
+pub fn to_cbor(value: &Value) -> Result<Vec<u8>> {
+    let cbor = ciborium::value::Value::from_value(value)?;
+    let mut res = vec![];
+    ciborium::ser::into_writer(&cbor, &mut res).map_err(|e| {
+        ERR_SERIALIZE
+            .msg_detail("内置Value对象编码为CBOR格式失败")
+            .cause(e)
+    })?;
+    OK(res)
+}
+
+/// 将CBOR格式的数据解码为内置Value对象
+///
+/// 支持标签0、1、100及1004的日期数据，带有时区的日期时间转换为UTC时间，标签4转换为Decimal
+pub fn from_cbor(bytes: &[u8]) -> Result<Value> {
+    let cbor: ciborium::value::Value = ciborium::de::from_reader(bytes)
+        .map_err(|e| ERR_DESERIALIZE.msg_detail("CBOR格式数据解码失败").cause(e))?;
+    cbor.as_value()
+}
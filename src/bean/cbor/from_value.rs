@@ -0,0 +1,77 @@
+use crate::{bean::FromValueTrait, iter::CollectResultTrait, Result, Value, OK};
+
+use super::codec::{
+    TAG_DATETIME_STRING, TAG_DATE_STRING, TAG_DECIMAL_FRACTION, TAG_NEGATIVE_BIGNUM,
+    TAG_POSITIVE_BIGNUM,
+};
+
+impl FromValueTrait for ciborium::value::Value {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Null => OK(ciborium::value::Value::Null),
+            Value::Bool(v) => OK(ciborium::value::Value::Bool(*v)),
+            Value::I32(v) => OK(ciborium::value::Value::Integer((*v).into())),
+            Value::I64(v) => OK(ciborium::value::Value::Integer((*v).into())),
+            Value::U32(v) => OK(ciborium::value::Value::Integer((*v).into())),
+            Value::U64(v) => OK(ciborium::value::Value::Integer((*v).into())),
+            Value::F32(v) => OK(ciborium::value::Value::Float(*v as f64)),
+            Value::F64(v) => OK(ciborium::value::Value::Float(*v)),
+            Value::Decimal(v) => OK(ciborium::value::Value::Tag(
+                TAG_DECIMAL_FRACTION,
+                Box::new(ciborium::value::Value::Array(vec![
+                    ciborium::value::Value::Integer((-(v.scale() as i64)).into()),
+                    mantissa(v.mantissa()),
+                ])),
+            )),
+            Value::Date(v) => OK(ciborium::value::Value::Tag(
+                TAG_DATE_STRING,
+                Box::new(ciborium::value::Value::Text(v.to_string())),
+            )),
+            Value::DateTime(v) => OK(ciborium::value::Value::Tag(
+                TAG_DATETIME_STRING,
+                Box::new(ciborium::value::Value::Text(
+                    v.to_chrono_datetime()
+                        .format("%Y-%m-%dT%H:%M:%SZ")
+                        .to_string(),
+                )),
+            )),
+            Value::Time(v) => OK(ciborium::value::Value::Text(v.to_string())),
+            Value::YearMonth(v) => OK(ciborium::value::Value::Text(v.to_string())),
+            Value::String(v) => OK(ciborium::value::Value::Text(v.clone())),
+            Value::Binary(v) => OK(ciborium::value::Value::Bytes(v.clone())),
+            Value::Array(arr) => arr
+                .iter()
+                .map(Self::from_value)
+                .collect_into_vec()
+                .map(ciborium::value::Value::Array),
+            Value::Object(obj) => {
+                let mut map = vec![];
+                for (k, v) in obj {
+                    map.push((
+                        ciborium::value::Value::Text(k.to_string()),
+                        Self::from_value(v)?,
+                    ));
+                }
+                OK(ciborium::value::Value::Map(map))
+            }
+        }
+    }
+}
+
+/// 尾数超出CBOR整数范围时使用大整数标签，参考[RFC8949](https://tools.ietf.org/html/rfc8949)
+fn mantissa(v: i128) -> ciborium::value::Value {
+    if let Ok(x) = ciborium::value::Integer::try_from(v) {
+        return ciborium::value::Value::Integer(x);
+    }
+    let (tag, n) = if v >= 0 {
+        (TAG_POSITIVE_BIGNUM, v as u128)
+    } else {
+        (TAG_NEGATIVE_BIGNUM, (-1 - v) as u128)
+    };
+    let bytes = n.to_be_bytes();
+    let start = bytes.iter().position(|x| *x != 0).unwrap_or(bytes.len());
+    ciborium::value::Value::Tag(
+        tag,
+        Box::new(ciborium::value::Value::Bytes(bytes[start..].to_vec())),
+    )
+}
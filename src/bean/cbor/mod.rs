@@ -0,0 +1,5 @@
+mod as_value;
+mod codec;
+mod from_value;
+
+pub use codec::{from_cbor, to_cbor};
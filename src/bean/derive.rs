@@ -0,0 +1,13 @@
+use crate::{error::AppError, Value};
+
+/// 派生宏生成的代码在字段处理失败时调用
+///
+/// 将字段路径以a.b.c的格式记录在异常上下文的field中，内层字段已记录的路径会追加在当前字段之后
+#[doc(hidden)]
+pub fn field_path_error(err: AppError, field: &str) -> AppError {
+    let path = match err.context_map_ref().and_then(|x| x.get("field")) {
+        Some(Value::String(inner)) => format!("{}.{}", field, inner),
+        _ => field.to_string(),
+    };
+    err.context_value("field".to_string(), Value::String(path))
+}
@@ -33,4 +33,8 @@ impl AsValueTrait for serde_json::Value {
             }
         }
     }
+
+    fn as_typed_value(&self) -> Result<Value> {
+        super::typed::as_typed_value(self)
+    }
 }
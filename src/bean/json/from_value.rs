@@ -18,6 +18,7 @@ impl FromValueTrait for serde_json::Value {
             Value::F64(v) => OK(serde_json::Value::Number(
                 serde_json::Number::from_f64(*v).unwrap(),
             )),
+            Value::Decimal(v) => OK(serde_json::Value::String(v.to_string())),
             Value::Date(v) => OK(serde_json::Value::String(v.to_string())),
             Value::Time(v) => OK(serde_json::Value::String(v.to_string())),
             Value::DateTime(v) => OK(serde_json::Value::String(v.to_string())),
@@ -40,4 +41,8 @@ impl FromValueTrait for serde_json::Value {
             }
         }
     }
+
+    fn from_typed_value(value: &Value) -> Result<Self> {
+        super::typed::from_typed_value(value)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{bean::MergeTrait, error::ERR_MERGE, Result, OK};
+use crate::{
+    bean::{AsValueTrait, FromValueTrait, MergeOptions, MergeTrait},
+    error::ERR_MERGE,
+    Result, OK,
+};
 
 impl MergeTrait for serde_json::Value {
     fn merge_self(&mut self, target: &Self) -> Result<Self> {
@@ -32,4 +36,11 @@ impl MergeTrait for serde_json::Value {
             _ => OK(target.clone()),
         }
     }
+
+    fn merge_with(&mut self, target: &Self, options: &MergeOptions) -> Result<Self> {
+        let mut value = self.as_value()?;
+        value.merge_with(&target.as_value()?, options)?;
+        *self = serde_json::Value::from_value(&value)?;
+        OK(self.clone())
+    }
 }
@@ -2,3 +2,4 @@ mod as_value;
 mod from_value;
 mod merge;
 mod pointer;
+mod typed;
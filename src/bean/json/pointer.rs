@@ -1,4 +1,10 @@
-use crate::bean::{base::parse_index, PointerTrait};
+use crate::{
+    bean::{
+        base::{parse_index, pointer_get_mut, pointer_remove, pointer_set, PointerNode},
+        PointerMutTrait, PointerTrait,
+    },
+    Result,
+};
 
 impl PointerTrait for serde_json::Value {
     type Context = serde_json::Value;
@@ -20,3 +26,64 @@ impl PointerTrait for serde_json::Value {
             })
     }
 }
+
+impl PointerMutTrait for serde_json::Value {
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context> {
+        pointer_get_mut(self, pointer)
+    }
+
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>> {
+        pointer_set(self, pointer, value)
+    }
+
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>> {
+        pointer_remove(self, pointer)
+    }
+}
+
+impl PointerNode for serde_json::Value {
+    fn new_array() -> Self {
+        serde_json::Value::Array(vec![])
+    }
+
+    fn new_object() -> Self {
+        serde_json::Value::Object(serde_json::Map::new())
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_null()
+    }
+
+    fn is_object(&self) -> bool {
+        self.is_object()
+    }
+
+    fn list(&self) -> Option<&Vec<Self>> {
+        self.as_array()
+    }
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.as_array_mut()
+    }
+
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        self.as_object().and_then(|x| x.get(key))
+    }
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self> {
+        self.as_object_mut().and_then(|x| x.get_mut(key))
+    }
+
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self> {
+        self.as_object_mut()
+            .and_then(|x| x.insert(key.to_string(), value))
+    }
+
+    fn remove_key(&mut self, key: &str) -> Option<Self> {
+        self.as_object_mut().and_then(|x| x.remove(key))
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::{
+        typed::{decode_typed, encode_typed, is_tag, TAG_OBJECT},
+        AsValueTrait, FromValueTrait,
+    },
+    error::ERR_CONVERT,
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
+
+/// 获取单键Object中的类型标记
+fn tag_of(obj: &serde_json::Map<String, serde_json::Value>) -> Option<(&str, &serde_json::Value)> {
+    if obj.len() != 1 {
+        return None;
+    }
+    let (k, v) = obj.iter().next()?;
+    k.strip_prefix('$').filter(|x| is_tag(x)).map(|x| (x, v))
+}
+
+pub(super) fn as_typed_value(value: &serde_json::Value) -> Result<Value> {
+    match value {
+        serde_json::Value::Array(v) => v
+            .iter()
+            .map(as_typed_value)
+            .collect_into_vec()
+            .map(Value::Array),
+        serde_json::Value::Object(o) => match tag_of(o) {
+            Some((TAG_OBJECT, serde_json::Value::Object(inner))) => as_typed_object(inner),
+            Some((TAG_OBJECT, _)) => {
+                Err(ERR_CONVERT.msg_detail("类型标记[object]对应的数据需为Object"))
+            }
+            Some((tag, v)) => decode_typed(tag, &v.as_value()?),
+            None => as_typed_object(o),
+        },
+        v => v.as_value(),
+    }
+}
+
+fn as_typed_object(obj: &serde_json::Map<String, serde_json::Value>) -> Result<Value> {
+    let mut map = BTreeMap::new();
+    for (k, v) in obj {
+        map.insert(k.to_string(), as_typed_value(v)?);
+    }
+    OK(Value::Object(map))
+}
+
+pub(super) fn from_typed_value(value: &Value) -> Result<serde_json::Value> {
+    if let Some((tag, v)) = encode_typed(value) {
+        return OK(tagged(tag, serde_json::Value::from_value(&v)?));
+    }
+    match value {
+        Value::Array(arr) => arr
+            .iter()
+            .map(from_typed_value)
+            .collect_into_vec()
+            .map(serde_json::Value::Array),
+        Value::Object(obj) => {
+            let mut map = serde_json::Map::new();
+            for (k, v) in obj {
+                map.insert(k.to_string(), from_typed_value(v)?);
+            }
+            if tag_of(&map).is_some() {
+                return OK(tagged(TAG_OBJECT, serde_json::Value::Object(map)));
+            }
+            OK(serde_json::Value::Object(map))
+        }
+        v => serde_json::Value::from_value(v),
+    }
+}
+
+fn tagged(tag: &str, value: serde_json::Value) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    map.insert(format!("${}", tag), value);
+    serde_json::Value::Object(map)
+}
@@ -0,0 +1,113 @@
+use crate::bean::escape_token;
+
+/// 数组合并策略
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrayStrategy {
+    /// 使用目标数组替换原有数组
+    Replace,
+    /// 将目标数组追加至原有数组尾部，目标不为数组时作为单个元素追加
+    Append,
+    /// 仅追加原有数组中不存在的元素
+    Union,
+    /// 数组元素为Object时，按指定字段匹配元素并递归合并，无法匹配的元素追加至尾部
+    MergeByKey(String),
+}
+
+/// 合并选项，用于MergeTrait::merge_with
+///
+/// 默认选项与merge_self行为一致：数组追加，标量以目标为准，类型不一致时返回ERR_MERGE异常
+///
+/// 可通过override_at按路径单独指定数组合并策略，路径格式同PointerTrait，
+/// 其中`*`可匹配任意一层的键或数组下标，如`/servers/*/ports`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeOptions {
+    array_strategy: ArrayStrategy,
+    null_as_delete: bool,
+    replace_on_mismatch: bool,
+    overrides: Vec<(Vec<String>, ArrayStrategy)>,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        MergeOptions {
+            array_strategy: ArrayStrategy::Append,
+            null_as_delete: false,
+            replace_on_mismatch: false,
+            overrides: vec![],
+        }
+    }
+}
+
+impl MergeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 符合[RFC7386](https://tools.ietf.org/html/rfc7386)的合并选项
+    ///
+    /// 数组整体替换，目标中的null表示删除对应的键，类型不一致时以目标为准
+    pub fn merge_patch() -> Self {
+        MergeOptions {
+            array_strategy: ArrayStrategy::Replace,
+            null_as_delete: true,
+            replace_on_mismatch: true,
+            overrides: vec![],
+        }
+    }
+
+    /// 设置默认的数组合并策略
+    pub fn array_strategy(mut self, strategy: ArrayStrategy) -> Self {
+        self.array_strategy = strategy;
+        self
+    }
+
+    /// 设置目标Object中值为null的键是否表示删除原有对象中的键
+    pub fn null_as_delete(mut self, value: bool) -> Self {
+        self.null_as_delete = value;
+        self
+    }
+
+    /// 设置类型不一致时是否使用目标对象替换原有对象，否则返回ERR_MERGE异常
+    pub fn replace_on_mismatch(mut self, value: bool) -> Self {
+        self.replace_on_mismatch = value;
+        self
+    }
+
+    /// 为指定路径设置数组合并策略，后设置的规则优先
+    pub fn override_at(mut self, pointer: &str, strategy: ArrayStrategy) -> Self {
+        let tokens = pointer.split('/').skip(1).map(|x| x.to_string()).collect();
+        self.overrides.push((tokens, strategy));
+        self
+    }
+
+    /// 获取指定路径上的数组合并策略，路径中各层级为转义后的键
+    pub(crate) fn array_strategy_at(&self, path: &[String]) -> &ArrayStrategy {
+        self.overrides
+            .iter()
+            .rev()
+            .find(|(tokens, _)| {
+                tokens.len() == path.len()
+                    && tokens
+                        .iter()
+                        .zip(path.iter())
+                        .all(|(t, p)| t == "*" || t == p)
+            })
+            .map(|(_, strategy)| strategy)
+            .unwrap_or(&self.array_strategy)
+    }
+
+    pub(crate) fn is_null_as_delete(&self) -> bool {
+        self.null_as_delete
+    }
+
+    pub(crate) fn is_replace_on_mismatch(&self) -> bool {
+        self.replace_on_mismatch
+    }
+}
+
+/// 将路径中的键转义后追加至路径尾部
+pub(crate) fn push_path(path: &[String], key: &str) -> Vec<String> {
+    let mut res = path.to_vec();
+    res.push(escape_token(key));
+    res
+}
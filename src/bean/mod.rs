@@ -2,12 +2,28 @@
 //!
 //! 通过内置对象实现对任意格式数据间的处理与转换
 mod base;
+mod cbor;
+mod derive;
 mod json;
+mod merge_options;
+mod msgpack;
 mod rbs;
+mod shared;
+mod tests;
+mod toml;
+mod typed;
 mod types;
 mod value;
 mod yaml;
 
+pub(crate) use base::{escape_token, key_to_pointer, parse_index, parse_insert_index};
 pub use base::{
-    AsValueTrait, DebugTrait, FromValueTrait, MergeTrait, MergeValueTrait, PointerTrait,
+    AsValueTrait, DebugTrait, FromValueTrait, MergeTrait, MergeValueTrait, PointerMutTrait,
+    PointerTrait,
 };
+pub use cbor::{from_cbor, to_cbor};
+pub use derive::field_path_error;
+pub use knife_util_derive::{AsValue, FromValue, MergeValue};
+pub use merge_options::{ArrayStrategy, MergeOptions};
+pub use msgpack::{from_msgpack, to_msgpack};
+pub use yaml::{register_yaml_tag, YamlTagResolver};
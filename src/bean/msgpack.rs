@@ -0,0 +1,27 @@
+use crate::{
+    error::{ERR_DESERIALIZE, ERR_SERIALIZE},
+    Result, Value,
+};
+
+/// 将内置Value对象编码为MessagePack格式
+///
+/// Binary编码为bin类型，日期类型编码为字符串
+///
+/// MessagePack没有对应的日期类型，解码后日期会成为Value::String，
+/// 需要时可通过`as_date`、`as_datetime`等方法按字符串重新解析
+pub fn to_msgpack(value: &Value) -> Result<Vec<u8>> {
+    rmp_serde::to_vec(value).map_err(|e| {
+        ERR_SERIALIZE
+            .msg_detail("内置Value对象编码为MessagePack格式失败")
+            .cause(e)
+    })
+}
+
+/// 将MessagePack格式的数据解码为内置Value对象
+pub fn from_msgpack(bytes: &[u8]) -> Result<Value> {
+    rmp_serde::from_slice(bytes).map_err(|e| {
+        ERR_DESERIALIZE
+            .msg_detail("MessagePack格式数据解码失败")
+            .cause(e)
+    })
+}
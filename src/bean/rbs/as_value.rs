@@ -1,6 +1,14 @@
 use std::collections::BTreeMap;
 
-use crate::{bean::AsValueTrait, error::ERR_INTERNAL, iter::CollectResultTrait, Result, Value, OK};
+use rust_decimal::Decimal;
+
+use crate::{
+    bean::AsValueTrait,
+    date::{Date, DateTime, Time},
+    error::ERR_CONVERT,
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
 
 impl AsValueTrait for rbs::Value {
     fn as_value(&self) -> Result<Value> {
@@ -23,13 +31,132 @@ impl AsValueTrait for rbs::Value {
             rbs::Value::Map(vm) => {
                 let mut map = BTreeMap::new();
                 for (k, v) in vm {
-                    map.insert(k.as_str().unwrap().to_string(), v.as_value().unwrap());
+                    let key = match k {
+                        rbs::Value::String(s) => s.clone(),
+                        k => k.to_string(),
+                    };
+                    map.insert(key, v.as_value()?);
                 }
                 OK(Value::Object(map))
             }
-            rbs::Value::Ext(ty, _buf) => Err(ERR_INTERNAL.msg_detail(
-                format!("不支持从rbs::Value转换Ext类型[{}]到内置Value对象", ty).as_str(),
-            )),
+            rbs::Value::Ext(ty, v) => ext_as_value(ty, v),
+        }
+    }
+}
+
+/// 转换rbdc及rbdc-pg中的扩展类型
+///
+/// 日期类型转换为对应的内置日期类型，其中Timestamp及Timestamptz为UTC时间的毫秒数，
+/// 带有时区偏移量的日期时间及rbdc-pg中带有时区的时间均转换为UTC时间，
+/// Decimal转换为内置Decimal类型，超出精度时保留为字符串，Json解析为内置Value对象，Uuid等其余类型按其内部数据进行转换
+fn ext_as_value(ty: &str, value: &rbs::Value) -> Result<Value> {
+    let res = match (ty, value) {
+        ("Date", rbs::Value::String(s)) => s
+            .get(..10)
+            .and_then(|x| Date::parse_str(x).ok())
+            .map(Value::Date),
+        ("Timez", rbs::Value::Map(m)) => m["time"]
+            .as_str()
+            .and_then(|x| chrono::NaiveTime::parse_from_str(x.get(..8)?, "%H:%M:%S").ok())
+            .zip(m["offset"].as_i64())
+            .and_then(|(time, offset)| utc_time(time, offset))
+            .map(Value::Time),
+        ("Timetz", rbs::Value::String(s)) if s.starts_with("OffsetTz") => {
+            parse_offset_time(s).map(Value::Time)
         }
+        ("Time", rbs::Value::String(s)) => parse_time(s).map(Value::Time),
+        ("DateTime", rbs::Value::String(s)) => parse_datetime(s).map(Value::DateTime),
+        ("Timestamp" | "Timestamptz", v) => v
+            .as_i64()
+            .and_then(from_timestamp_millis)
+            .map(Value::DateTime),
+        ("Decimal", rbs::Value::String(s)) => match Decimal::from_str_exact(s) {
+            Ok(v) => Some(Value::Decimal(v)),
+            // 超出Decimal精度或范围的数值及NaN等特殊值保留为字符串，避免丢失数据
+            Err(_) if s.parse::<f64>().is_ok() => Some(Value::String(s.clone())),
+            Err(_) => None,
+        },
+        ("Json", rbs::Value::String(s)) => {
+            return serde_json::from_str::<serde_json::Value>(s)
+                .map_err(|e| {
+                    ERR_CONVERT
+                        .msg_detail("rbs::Value中Json类型的数据不是合法的Json格式")
+                        .cause(e)
+                })
+                .and_then(|x| x.as_value());
+        }
+        _ => return value.as_value(),
+    };
+    res.ok_or_else(|| {
+        ERR_CONVERT
+            .msg_detail(format!("rbs::Value中Ext类型[{}]的数据[{}]不合法", ty, value).as_str())
+    })
+}
+
+/// 解析时间，忽略毫秒及时区部分
+fn parse_time(s: &str) -> Option<Time> {
+    chrono::NaiveTime::parse_from_str(s.get(..8)?, "%H:%M:%S")
+        .ok()
+        .and_then(|x| Time::from_time(x).ok())
+}
+
+/// 解析rbdc-pg中Timetz转换为rbs::Value时输出的文本，
+/// 如：`OffsetTz { time: Time { nano: 0, sec: 5, min: 30, hour: 12 }, offset: -28800 }`
+fn parse_offset_time(s: &str) -> Option<Time> {
+    let field = |name: &str| -> Option<i64> {
+        let start = s.find(format!("{}: ", name).as_str())? + name.len() + 2;
+        let end = s[start..].find([',', ' ', '}'])? + start;
+        s[start..end].parse().ok()
+    };
+    let time = chrono::NaiveTime::from_hms_opt(
+        u32::try_from(field("hour")?).ok()?,
+        u32::try_from(field("min")?).ok()?,
+        u32::try_from(field("sec")?).ok()?,
+    )?;
+    utc_time(time, field("offset")?)
+}
+
+/// 按偏移量转换为UTC时间，偏移量沿用PostgreSQL的约定，为UTC以西的秒数
+fn utc_time(time: chrono::NaiveTime, offset: i64) -> Option<Time> {
+    let (time, _) = time.overflowing_add_signed(chrono::Duration::seconds(offset));
+    Time::from_time(time).ok()
+}
+
+/// 解析日期时间，日期与时间之间可使用空格或T分隔，忽略毫秒部分，带有时区偏移量时转换为UTC时间
+fn parse_datetime(s: &str) -> Option<DateTime> {
+    let local = chrono::NaiveDateTime::parse_from_str(
+        s.get(..19)?.replacen('T', " ", 1).as_str(),
+        "%Y-%m-%d %H:%M:%S",
+    )
+    .ok()?;
+    let offset =
+        parse_offset(s[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit()))?;
+    let datetime = local.checked_sub_signed(chrono::Duration::seconds(offset))?;
+    DateTime::from_datetime(datetime).ok()
+}
+
+/// 解析`Z`、`+08:00`、`-0530`及`+08`格式的时区偏移量，返回UTC以东的秒数，无偏移量时视为UTC时间
+fn parse_offset(s: &str) -> Option<i64> {
+    let sign = match s.chars().next() {
+        None | Some('Z') if s.len() <= 1 => return Some(0),
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return None,
+    };
+    let digits = s[1..].replacen(':', "", 1);
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
     }
+    let (hour, min) = match digits.len() {
+        2 => (digits.as_str(), "0"),
+        4 => digits.split_at(2),
+        _ => return None,
+    };
+    Some(sign * (hour.parse::<i64>().ok()? * 3600 + min.parse::<i64>().ok()? * 60))
+}
+
+fn from_timestamp_millis(millis: i64) -> Option<DateTime> {
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?.and_hms_opt(0, 0, 0)?;
+    let datetime = epoch.checked_add_signed(chrono::Duration::milliseconds(millis))?;
+    DateTime::from_datetime(datetime).ok()
 }
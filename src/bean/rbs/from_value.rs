@@ -1,6 +1,4 @@
-use crate::{
-    bean::FromValueTrait, error::ERR_INTERNAL, iter::CollectResultTrait, Result, Value, OK,
-};
+use crate::{bean::FromValueTrait, iter::CollectResultTrait, Result, Value, OK};
 
 impl FromValueTrait for rbs::Value {
     fn from_value(value: &Value) -> Result<Self> {
@@ -13,6 +11,10 @@ impl FromValueTrait for rbs::Value {
             Value::U64(v) => OK(rbs::Value::U64(*v)),
             Value::F32(v) => OK(rbs::Value::F32(*v)),
             Value::F64(v) => OK(rbs::Value::F64(*v)),
+            Value::Decimal(v) => OK(rbs::Value::Ext(
+                "Decimal",
+                Box::new(rbs::Value::String(v.to_string())),
+            )),
             Value::Date(v) => OK(rbs::Value::Ext(
                 "Date",
                 Box::new(rbs::Value::String(v.to_string())),
@@ -25,12 +27,10 @@ impl FromValueTrait for rbs::Value {
                 "DateTime",
                 Box::new(rbs::Value::String(v.to_string())),
             )),
-            Value::YearMonth(v) => Err(ERR_INTERNAL.msg_detail(
-                format!(
-                    "不支持从内置Value对象转换YearMonth类型[{:?}]到rbs::Value",
-                    v
-                )
-                .as_str(),
+            // rbdc中没有年月类型，按当月第一天的日期处理
+            Value::YearMonth(v) => OK(rbs::Value::Ext(
+                "Date",
+                Box::new(rbs::Value::String(format!("{}-01", v.to_string()))),
             )),
             Value::String(v) => OK(rbs::Value::String(v.clone())),
             Value::Binary(v) => OK(rbs::Value::Binary(v.clone())),
@@ -0,0 +1,16 @@
+use crate::{
+    bean::{AsValueTrait, FromValueTrait},
+    Result, SharedValue, Value, OK,
+};
+
+impl AsValueTrait for SharedValue {
+    fn as_value(&self) -> Result<Value> {
+        OK(self.to_value())
+    }
+}
+
+impl FromValueTrait for SharedValue {
+    fn from_value(value: &Value) -> Result<Self> {
+        OK(SharedValue::from(value.clone()))
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{context::ContextTrait, Result, SharedValue, Value, OK};
+
+/// 仅将读取的子节点转换为内置Value对象并缓存在该子节点上，未修改的子节点在写时复制后仍保留缓存
+impl ContextTrait for SharedValue {
+    type Context = SharedValue;
+
+    fn get_value(&self, key: &str) -> Result<Option<&Value>> {
+        OK(self.get(key).map(SharedValue::as_value_ref))
+    }
+
+    fn insert_value(&mut self, key: &str, value: Value) -> Result<()> {
+        self.insert(key, SharedValue::from(value)).map(|_| ())
+    }
+}
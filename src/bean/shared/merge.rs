@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::{merge_options::push_path, value::merge as merge_value, MergeOptions, MergeTrait},
+    value_type::Node,
+    Result, SharedValue, Value, OK,
+};
+
+/// Object之间的合并仅复制被修改的节点，其余子树与合并前的对象共享，
+/// 标量直接共享目标对象中的节点，Array的合并按内置Value对象进行处理
+impl MergeTrait for SharedValue {
+    fn merge_self(&mut self, target: &Self) -> Result<Self> {
+        self.merge_with(target, &MergeOptions::default())
+    }
+
+    fn merge_with(&mut self, target: &Self, options: &MergeOptions) -> Result<Self> {
+        merge(self, target, options, &[])?;
+        OK(self.clone())
+    }
+}
+
+fn merge(
+    source: &mut SharedValue,
+    target: &SharedValue,
+    options: &MergeOptions,
+    path: &[String],
+) -> Result<()> {
+    match (source.is_object(), target.is_object()) {
+        (true, true) => {
+            if let Node::Object(obj) = source.node_mut() {
+                for (k2, v2) in target.entries() {
+                    if options.is_null_as_delete() && matches!(v2.scalar(), Some(Value::Null)) {
+                        obj.remove(k2);
+                    } else if let Some(v) = obj.get_mut(k2) {
+                        merge(v, v2, options, &push_path(path, k2))?;
+                    } else {
+                        obj.insert(k2.to_string(), replacement(v2, options));
+                    }
+                }
+            }
+            OK(())
+        }
+        _ if source.scalar().is_some() => {
+            *source = replacement(target, options);
+            OK(())
+        }
+        _ => {
+            let mut value = source.to_value();
+            merge_value(&mut value, &target.to_value(), options, path)?;
+            *source = SharedValue::from(value);
+            OK(())
+        }
+    }
+}
+
+/// 获取用于替换原有对象的数据，null表示删除时需移除目标Object中值为null的键
+fn replacement(target: &SharedValue, options: &MergeOptions) -> SharedValue {
+    if !options.is_null_as_delete() || !target.is_object() {
+        return target.clone();
+    }
+    let mut map = BTreeMap::new();
+    for (k, v) in target.entries() {
+        if !matches!(v.scalar(), Some(Value::Null)) {
+            map.insert(k.to_string(), replacement(v, options));
+        }
+    }
+    SharedValue::object(map)
+}
@@ -0,0 +1,4 @@
+mod as_value;
+mod context;
+mod merge;
+mod pointer;
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::{
+        base::{
+            parse_index, parse_pointer, pointer_get_mut, pointer_remove, pointer_set, PointerNode,
+        },
+        PointerMutTrait, PointerTrait,
+    },
+    value_type::Node,
+    Result, SharedValue, Value,
+};
+
+impl PointerTrait for SharedValue {
+    type Context = SharedValue;
+    fn p(&self, pointer: &str) -> Option<&Self::Context> {
+        parse_pointer(pointer)
+            .ok()?
+            .into_iter()
+            .try_fold(self, |target, token| match target.node() {
+                Node::Array(list) => parse_index(&token).and_then(|x| list.get(x)),
+                Node::Object(map) => map.get(&token),
+                Node::Scalar(_) => None,
+            })
+    }
+}
+
+/// 获取可变引用时，路径上被共享的节点均会被复制
+impl PointerMutTrait for SharedValue {
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context> {
+        pointer_get_mut(self, pointer)
+    }
+
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>> {
+        pointer_set(self, pointer, value)
+    }
+
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>> {
+        pointer_remove(self, pointer)
+    }
+}
+
+/// 仅在写入时复制路径上被共享的节点
+impl PointerNode for SharedValue {
+    fn new_array() -> Self {
+        SharedValue::array(vec![])
+    }
+
+    fn new_object() -> Self {
+        SharedValue::object(BTreeMap::new())
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self.scalar(), Some(Value::Null))
+    }
+
+    fn is_object(&self) -> bool {
+        matches!(self.node(), Node::Object(_))
+    }
+
+    fn list(&self) -> Option<&Vec<Self>> {
+        match self.node() {
+            Node::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>> {
+        if !matches!(self.node(), Node::Array(_)) {
+            return None;
+        }
+        match self.node_mut() {
+            Node::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        self.get(key)
+    }
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self> {
+        self.get(key)?;
+        match self.node_mut() {
+            Node::Object(map) => map.get_mut(key),
+            _ => None,
+        }
+    }
+
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self> {
+        match self.node_mut() {
+            Node::Object(map) => map.insert(key.to_string(), value),
+            _ => None,
+        }
+    }
+
+    fn remove_key(&mut self, key: &str) -> Option<Self> {
+        self.get(key)?;
+        match self.node_mut() {
+            Node::Object(map) => map.remove(key),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,675 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, str::FromStr};
+
+    use rust_decimal::Decimal;
+
+    use crate::{
+        bean::{
+            from_cbor, from_msgpack, register_yaml_tag, to_cbor, to_msgpack, ArrayStrategy,
+            AsValue, AsValueTrait, FromValue, FromValueTrait, MergeOptions, MergeTrait, MergeValue,
+            MergeValueTrait, PointerMutTrait, PointerTrait,
+        },
+        date::{Date, DateTime, Time, YearMonth},
+        Value, OK,
+    };
+
+    fn default_port() -> i32 {
+        8080
+    }
+
+    #[derive(AsValue, FromValue, MergeValue, Clone, Default, Debug)]
+    struct Server {
+        host: String,
+        #[knife(default = "default_port")]
+        port: i32,
+        #[knife(rename = "startDate")]
+        start_date: Option<Date>,
+        #[knife(skip)]
+        runtime: u64,
+        #[knife(flatten)]
+        extra: Extra,
+        #[knife(default)]
+        nodes: Vec<Node>,
+    }
+
+    #[derive(AsValue, FromValue, MergeValue, Clone, Default, Debug)]
+    struct Extra {
+        debug: bool,
+    }
+
+    #[derive(AsValue, FromValue, MergeValue, Clone, Default, Debug)]
+    struct Node {
+        name: String,
+        weight: i64,
+    }
+
+    fn object(items: Vec<(&str, Value)>) -> Value {
+        Value::Object(BTreeMap::from_iter(
+            items.into_iter().map(|(k, v)| (k.to_string(), v)),
+        ))
+    }
+
+    #[test]
+    fn test_derive() {
+        let value = object(vec![
+            ("host", Value::String("localhost".to_string())),
+            ("startDate", Value::String("2022-10-01".to_string())),
+            ("runtime", Value::U64(5)),
+            ("debug", Value::Bool(true)),
+        ]);
+        let server = Server::from_value(&value).unwrap();
+        assert_eq!(server.host, "localhost");
+        assert_eq!(server.port, 8080);
+        assert_eq!(server.start_date.unwrap().to_string(), "2022-10-01");
+        assert_eq!(server.runtime, 0);
+        assert!(server.extra.debug);
+        assert!(server.nodes.is_empty());
+
+        let res = server.as_value().unwrap();
+        let obj = res.as_object().unwrap();
+        assert!(matches!(obj.get("startDate"), Some(Value::Date(_))));
+        assert!(matches!(obj.get("debug"), Some(Value::Bool(true))));
+        assert!(!obj.contains_key("runtime"));
+        assert!(!obj.contains_key("extra"));
+
+        let mut server2 = server.clone();
+        server2
+            .merge_value(Some(&object(vec![
+                ("port", Value::I32(9090)),
+                (
+                    "nodes",
+                    Value::Array(vec![object(vec![
+                        ("name", Value::String("a".to_string())),
+                        ("weight", Value::I64(1)),
+                    ])]),
+                ),
+            ])))
+            .unwrap();
+        assert_eq!(server2.port, 9090);
+        assert_eq!(server2.host, "localhost");
+        assert_eq!(server2.nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_derive_error_path() {
+        let value = object(vec![
+            ("host", Value::String("localhost".to_string())),
+            ("debug", Value::Bool(false)),
+            (
+                "nodes",
+                Value::Array(vec![object(vec![
+                    ("name", Value::String("a".to_string())),
+                    ("weight", Value::String("x".to_string())),
+                ])]),
+            ),
+        ]);
+        let err = Server::from_value(&value).unwrap_err();
+        let field = err.context_map_ref().unwrap().get("field").unwrap();
+        assert_eq!(field.as_str().unwrap(), "nodes.0.weight");
+
+        #[derive(AsValue)]
+        struct Wrapper {
+            #[knife(flatten, rename = "extraTags")]
+            extra_tags: Vec<String>,
+        }
+        let err = Wrapper { extra_tags: vec![] }.as_value().unwrap_err();
+        let field = err.context_map_ref().unwrap().get("field").unwrap();
+        assert_eq!(field.as_str().unwrap(), "extraTags");
+    }
+
+    #[test]
+    fn test_pointer_mut() {
+        let mut value = object(vec![("a", object(vec![("b", Value::I32(1))]))]);
+        *value.p_mut("/a/b").unwrap() = Value::I32(2);
+        assert_eq!(value.p("/a/b").unwrap().as_i32().unwrap(), 2);
+
+        let old = value.set_by_pointer("/a/b", Value::I32(3)).unwrap();
+        assert_eq!(old.unwrap().as_i32().unwrap(), 2);
+        assert!(value
+            .set_by_pointer("/x/list/-/name", Value::String("n".to_string()))
+            .unwrap()
+            .is_none());
+        assert!(value.p("/x/list").unwrap().is_array());
+        assert_eq!(value.p("/x/list/0/name").unwrap().as_str().unwrap(), "n");
+        assert!(value.set_by_pointer("/x/list/5", Value::Null).is_err());
+        assert!(value.set_by_pointer("/a/b/c", Value::Null).is_err());
+        // 写入失败时不会留下中途创建的节点
+        assert!(value.set_by_pointer("/y/list/5", Value::Null).is_err());
+        assert!(value.p("/y").is_none());
+
+        let removed = value.remove_by_pointer("/x/list/0").unwrap();
+        assert!(removed.is_some());
+        assert!(value.p("/x/list").unwrap().as_array().unwrap().is_empty());
+        assert!(value.remove_by_pointer("/not/exist").unwrap().is_none());
+
+        let mut json = serde_json::json!({"a": [1]});
+        json.set_by_pointer("/a/-", serde_json::json!(2)).unwrap();
+        json.set_by_pointer("/b/c", serde_json::json!("x")).unwrap();
+        assert_eq!(json, serde_json::json!({"a": [1, 2], "b": {"c": "x"}}));
+        assert!(json.set_by_pointer("/d/e/3", serde_json::json!(1)).is_err());
+        assert!(json.set_by_pointer("/a/0/x", serde_json::json!(1)).is_err());
+        assert_eq!(json, serde_json::json!({"a": [1, 2], "b": {"c": "x"}}));
+        assert_eq!(
+            json.remove_by_pointer("/a/0").unwrap(),
+            Some(serde_json::json!(1))
+        );
+
+        let mut yaml: serde_yaml::Value = serde_yaml::from_str(
+            "a:
+  b: 1",
+        )
+        .unwrap();
+        yaml.set_by_pointer("/a/c/0", serde_yaml::Value::Bool(true))
+            .unwrap();
+        *yaml.p_mut("/a/b").unwrap() = serde_yaml::Value::Bool(false);
+        assert_eq!(yaml.p("/a/c/0"), Some(&serde_yaml::Value::Bool(true)));
+        assert_eq!(yaml.p("/a/b"), Some(&serde_yaml::Value::Bool(false)));
+        assert!(yaml.remove_by_pointer("/a/b").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_merge_with() {
+        let mut base = serde_json::json!({
+            "name": "app",
+            "tags": ["a", "b"],
+            "profiles": ["dev"],
+            "servers": [{"name": "s1", "port": 80}, {"name": "s2", "port": 81}],
+            "debug": true
+        });
+        let layer = serde_json::json!({
+            "tags": ["b", "c"],
+            "profiles": ["prod"],
+            "servers": [{"name": "s2", "port": 8081}, {"name": "s3", "port": 82}],
+            "debug": null
+        });
+        let options = MergeOptions::merge_patch()
+            .override_at("/tags", ArrayStrategy::Union)
+            .override_at("/servers", ArrayStrategy::MergeByKey("name".to_string()));
+        base.merge_with(&layer, &options).unwrap();
+        assert_eq!(
+            base,
+            serde_json::json!({
+                "name": "app",
+                "tags": ["a", "b", "c"],
+                "profiles": ["prod"],
+                "servers": [
+                    {"name": "s1", "port": 80},
+                    {"name": "s2", "port": 8081},
+                    {"name": "s3", "port": 82}
+                ]
+            })
+        );
+
+        let mut value = serde_json::json!({"a": [1], "b": {"c": 1}})
+            .as_value()
+            .unwrap();
+        let target = serde_json::json!({"a": [1, 2], "b": {"d": null}})
+            .as_value()
+            .unwrap();
+        let res = value.merge_with(&target, &MergeOptions::new()).unwrap();
+        assert_eq!(
+            serde_json::Value::from_value(&res).unwrap(),
+            serde_json::json!({"a": [1, 1, 2], "b": {"c": 1, "d": null}})
+        );
+
+        let mut value = serde_json::json!({"a": {"b": 1}}).as_value().unwrap();
+        let err = value
+            .merge_with(
+                &serde_json::json!({"a": 1}).as_value().unwrap(),
+                &MergeOptions::new(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.context_map_ref()
+                .unwrap()
+                .get("pointer")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "/a"
+        );
+        let res = value
+            .merge_with(
+                &serde_json::json!({"a": 1}).as_value().unwrap(),
+                &MergeOptions::new().replace_on_mismatch(true),
+            )
+            .unwrap();
+        assert_eq!(res.p("/a").unwrap().as_i64().unwrap(), 1);
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Counter(i32);
+        impl MergeTrait for Counter {
+            fn merge_self(&mut self, target: &Self) -> crate::Result<Self> {
+                self.0 += target.0;
+                OK(self.clone())
+            }
+        }
+        let mut counter = Counter(1);
+        let res = counter
+            .merge_with(&Counter(2), &MergeOptions::new())
+            .unwrap();
+        assert_eq!(res, Counter(3));
+        let err = counter
+            .merge_with(&Counter(2), &MergeOptions::merge_patch())
+            .unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_MERGE");
+        assert_eq!(counter, Counter(3));
+    }
+
+    #[test]
+    fn test_toml() {
+        let toml: toml::Value = toml::from_str(
+            r#"
+            name = "app"
+            created = 2022-10-01T12:30:00
+            updated = 2022-10-01T12:30:00+08:00
+            day = 2022-10-01
+            [server]
+            ports = [80, 443]
+            "#,
+        )
+        .unwrap();
+        let value = toml.as_value().unwrap();
+        assert_eq!(
+            value
+                .p("/created")
+                .unwrap()
+                .as_datetime()
+                .unwrap()
+                .to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert_eq!(
+            value
+                .p("/updated")
+                .unwrap()
+                .as_datetime()
+                .unwrap()
+                .to_string(),
+            "2022-10-01 04:30:00"
+        );
+        assert!(matches!(value.p("/day"), Some(Value::Date(_))));
+
+        let mut toml2 = toml::Value::from_value(&value).unwrap();
+        assert_eq!(toml2.get("created"), toml.get("created"));
+        assert_eq!(toml2.get("day"), toml.get("day"));
+        assert_eq!(
+            toml2.p("/server/ports/1").unwrap().as_integer().unwrap(),
+            443
+        );
+        toml2
+            .set_by_pointer("/a/list/-", toml::Value::Boolean(true))
+            .unwrap();
+        assert_eq!(toml2.p("/a/list/0"), Some(&toml::Value::Boolean(true)));
+        assert!(toml2.remove_by_pointer("/name").unwrap().is_some());
+        assert!(toml::Value::from_value(&Value::Binary(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_msgpack_cbor() {
+        let value = Value::Object(BTreeMap::from([
+            ("bin".to_string(), Value::Binary(vec![0, 1, 255])),
+            (
+                "date".to_string(),
+                Value::Date(Date::parse_str("2022-10-01").unwrap()),
+            ),
+            (
+                "time".to_string(),
+                Value::DateTime(DateTime::parse_str("2022-10-01 12:30:00").unwrap()),
+            ),
+            (
+                "list".to_string(),
+                Value::Array(vec![Value::I64(-1), Value::String("a".to_string())]),
+            ),
+        ]));
+
+        let res = from_msgpack(&to_msgpack(&value).unwrap()).unwrap();
+        assert_eq!(
+            res.p("/bin").unwrap().as_binary().unwrap(),
+            &vec![0, 1, 255]
+        );
+        assert_eq!(res.p("/date").unwrap().as_str().unwrap(), "2022-10-01");
+        assert!(res.p("/date").unwrap().as_date().is_ok());
+        assert_eq!(res.p("/list/0").unwrap().as_i64().unwrap(), -1);
+
+        let res = from_cbor(&to_cbor(&value).unwrap()).unwrap();
+        assert_eq!(
+            res.p("/bin").unwrap().as_binary().unwrap(),
+            &vec![0, 1, 255]
+        );
+        assert!(matches!(res.p("/date"), Some(Value::Date(_))));
+        assert_eq!(
+            res.p("/time").unwrap().as_datetime().unwrap().to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert_eq!(res.p("/list/1").unwrap().as_str().unwrap(), "a");
+        assert!(from_cbor(&[0xff]).is_err());
+    }
+
+    #[test]
+    fn test_typed_value() {
+        let value = Value::Object(BTreeMap::from([
+            ("bin".to_string(), Value::Binary(vec![0, 1, 255])),
+            (
+                "date".to_string(),
+                Value::Date(Date::parse_str("2022-10-01").unwrap()),
+            ),
+            (
+                "time".to_string(),
+                Value::Time(Time::parse_str("12:30:00").unwrap()),
+            ),
+            (
+                "datetime".to_string(),
+                Value::DateTime(DateTime::parse_str("2022-10-01 12:30:00").unwrap()),
+            ),
+            (
+                "month".to_string(),
+                Value::YearMonth(YearMonth::parse_str("2022-10").unwrap()),
+            ),
+            (
+                "numbers".to_string(),
+                Value::Array(vec![
+                    Value::I32(-1),
+                    Value::I64(2),
+                    Value::U32(3),
+                    Value::U64(4),
+                    Value::F32(1.5),
+                    Value::F64(f64::INFINITY),
+                ]),
+            ),
+            (
+                "raw".to_string(),
+                Value::Object(BTreeMap::from([(
+                    "$date".to_string(),
+                    Value::String("not a date".to_string()),
+                )])),
+            ),
+        ]));
+
+        let json = serde_json::Value::from_typed_value(&value).unwrap();
+        assert_eq!(json["bin"], serde_json::json!({"$binary": "AAH/"}));
+        assert_eq!(json["date"], serde_json::json!({"$date": "2022-10-01"}));
+        let text = serde_json::to_string(&json).unwrap();
+        let json2: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+        let res = json2.as_typed_value().unwrap();
+        assert_eq!(format!("{:?}", res), format!("{:?}", value));
+
+        let yaml = serde_yaml::Value::from_typed_value(&value).unwrap();
+        let text = serde_yaml::to_string(&yaml).unwrap();
+        assert!(text.contains("date: !date 2022-10-01"));
+        let yaml2: serde_yaml::Value = serde_yaml::from_str(text.as_str()).unwrap();
+        let res = yaml2.as_typed_value().unwrap();
+        assert_eq!(format!("{:?}", res), format!("{:?}", value));
+
+        let bad = serde_json::json!({"$date": "2022-13-01"});
+        assert!(bad.as_typed_value().is_err());
+        assert!(serde_json::json!({"$other": 1})
+            .as_typed_value()
+            .unwrap()
+            .p("/$other")
+            .is_some());
+    }
+
+    #[test]
+    fn test_yaml_tags() {
+        std::env::set_var("KNIFE_UTIL_TEST_YAML_TAG", "secret");
+        register_yaml_tag("upper", |v| {
+            OK(Value::String(v.as_str().unwrap_or("").to_uppercase()))
+        })
+        .unwrap();
+        let yaml: serde_yaml::Value = serde_yaml::from_str(
+            r#"
+base: &base
+  replicas: 1
+  image: nginx
+app:
+  <<: *base
+  replicas: 3
+day: !date 2022-10-01
+created: !datetime 2022-10-01T12:30:00
+data: !binary AAH/
+password: !env KNIFE_UTIL_TEST_YAML_TAG
+name: !upper knife
+ports:
+  80: http
+  true: enabled
+  ~: none
+"#,
+        )
+        .unwrap();
+        let value = yaml.as_value().unwrap();
+        assert_eq!(value.p("/app/replicas").unwrap().as_i64().unwrap(), 3);
+        assert_eq!(value.p("/app/image").unwrap().as_str().unwrap(), "nginx");
+        assert!(matches!(value.p("/day"), Some(Value::Date(_))));
+        assert_eq!(
+            value
+                .p("/created")
+                .unwrap()
+                .as_datetime()
+                .unwrap()
+                .to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert_eq!(
+            value.p("/data").unwrap().as_binary().unwrap(),
+            &vec![0, 1, 255]
+        );
+        assert_eq!(value.p("/password").unwrap().as_str().unwrap(), "secret");
+        assert_eq!(value.p("/name").unwrap().as_str().unwrap(), "KNIFE");
+        assert_eq!(value.p("/ports/80").unwrap().as_str().unwrap(), "http");
+        assert_eq!(value.p("/ports/true").unwrap().as_str().unwrap(), "enabled");
+        assert_eq!(value.p("/ports/null").unwrap().as_str().unwrap(), "none");
+
+        let missing: serde_yaml::Value =
+            serde_yaml::from_str("a: !env KNIFE_UTIL_TEST_NOT_EXIST").unwrap();
+        assert_eq!(missing.as_value().unwrap_err().name_ref(), "ERR_ENV_VAR");
+        let unknown: serde_yaml::Value = serde_yaml::from_str("a: !unknown 1").unwrap();
+        assert!(unknown.as_value().is_err());
+        let duplicated: serde_yaml::Value = serde_yaml::from_str("1: int\n'1': str").unwrap();
+        assert_eq!(duplicated.as_value().unwrap_err().name_ref(), "ERR_DATA");
+
+        let typed: serde_yaml::Value = serde_yaml::from_str(
+            "base: &base\n  day: !date 2022-10-01\n  replicas: 1\napp:\n  <<: *base\n  replicas: 3\n",
+        )
+        .unwrap();
+        let value = typed.as_typed_value().unwrap();
+        assert!(matches!(value.p("/app/day"), Some(Value::Date(_))));
+        assert_eq!(value.p("/app/replicas").unwrap().as_i64().unwrap(), 3);
+        assert!(value.p("/app/<<").is_none());
+    }
+
+    #[test]
+    fn test_rbs_ext() {
+        let mut row = rbs::value::map::ValueMap::new();
+        let ext = |ty: &'static str, v: rbs::Value| rbs::Value::Ext(ty, Box::new(v));
+        row.insert(
+            rbs::Value::String("day".to_string()),
+            ext("Date", rbs::Value::String("2022-10-01".to_string())),
+        );
+        row.insert(
+            rbs::Value::String("at".to_string()),
+            ext("Time", rbs::Value::String("12:30:00.5".to_string())),
+        );
+        row.insert(
+            rbs::Value::String("created".to_string()),
+            ext(
+                "DateTime",
+                rbs::Value::String("2022-10-01 12:30:00.000001".to_string()),
+            ),
+        );
+        row.insert(
+            rbs::Value::String("ts".to_string()),
+            ext("Timestamp", rbs::Value::U64(1664627400000)),
+        );
+        row.insert(
+            rbs::Value::String("amount".to_string()),
+            ext("Decimal", rbs::Value::String("12.50".to_string())),
+        );
+        row.insert(
+            rbs::Value::String("extra".to_string()),
+            ext("Json", rbs::Value::String(r#"{"a": [1]}"#.to_string())),
+        );
+        row.insert(rbs::Value::I32(1), rbs::Value::Bool(true));
+        let value = rbs::Value::Map(row).as_value().unwrap();
+        assert!(matches!(value.p("/day"), Some(Value::Date(_))));
+        assert_eq!(
+            value.p("/at").unwrap().as_time().unwrap().to_string(),
+            "12:30:00"
+        );
+        assert_eq!(
+            value
+                .p("/created")
+                .unwrap()
+                .as_datetime()
+                .unwrap()
+                .to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert_eq!(
+            value.p("/ts").unwrap().as_datetime().unwrap().to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert!(matches!(value.p("/amount"), Some(Value::Decimal(_))));
+        assert_eq!(
+            value
+                .p("/amount")
+                .unwrap()
+                .as_decimal()
+                .unwrap()
+                .to_string(),
+            "12.50"
+        );
+        assert_eq!(value.p("/extra/a/0").unwrap().as_i64().unwrap(), 1);
+        assert!(value.p("/1").unwrap().as_bool().unwrap());
+
+        // rbdc-pg中的Timetz序列化为Timez，偏移量为UTC以西的秒数
+        let timetz = rbdc_pg::types::timetz::Timetz(rbdc_pg::types::timetz::OffsetTz {
+            time: "12:30:05.5".parse().unwrap(),
+            offset: -28800,
+        });
+        let serialized = rbs::to_value(&timetz).unwrap().as_value().unwrap();
+        assert_eq!(serialized.as_time().unwrap().to_string(), "04:30:05");
+        let decoded = rbs::Value::from(timetz).as_value().unwrap();
+        assert_eq!(decoded.as_time().unwrap().to_string(), "04:30:05");
+        let time = rbatis::rbdc::types::time::Time("23:00:00".parse().unwrap());
+        let converted = rbs::to_value(&time).unwrap().as_value().unwrap();
+        assert_eq!(converted.as_time().unwrap().to_string(), "23:00:00");
+        let converted = rbs::to_value(rbatis::rbdc::types::date::Date(
+            "2022-10-01".parse().unwrap(),
+        ))
+        .unwrap()
+        .as_value()
+        .unwrap();
+        assert!(matches!(converted, Value::Date(_)));
+        let converted = rbs::to_value(rbatis::rbdc::types::decimal::Decimal("1.50".to_string()))
+            .unwrap()
+            .as_value()
+            .unwrap();
+        assert_eq!(converted.as_decimal().unwrap().to_string(), "1.50");
+
+        // 超出Decimal精度的数值保留为字符串，带有时区偏移量的日期时间转换为UTC时间
+        let precise = "12345678901234567890.123456789012345";
+        let converted = ext("Decimal", rbs::Value::String(precise.to_string()))
+            .as_value()
+            .unwrap();
+        assert_eq!(converted, Value::String(precise.to_string()));
+        for (s, expected) in [
+            ("2022-10-01T20:30:00.5+08:00", "2022-10-01 12:30:00"),
+            ("2022-10-01 07:00:00-0530", "2022-10-01 12:30:00"),
+            ("2022-10-01T12:30:00Z", "2022-10-01 12:30:00"),
+            ("2022-10-02 02:00:00+13:30", "2022-10-01 12:30:00"),
+        ] {
+            let converted = ext("DateTime", rbs::Value::String(s.to_string()))
+                .as_value()
+                .unwrap();
+            assert_eq!(converted.as_datetime().unwrap().to_string(), expected);
+        }
+        assert!(ext(
+            "DateTime",
+            rbs::Value::String("2022-10-01 12:30:00 abc".to_string())
+        )
+        .as_value()
+        .is_err());
+
+        let month = Value::YearMonth(YearMonth::parse_str("2022-10").unwrap());
+        let rbs = rbs::Value::from_value(&month).unwrap();
+        assert_eq!(
+            rbs,
+            rbs::Value::Ext(
+                "Date",
+                Box::new(rbs::Value::String("2022-10-01".to_string()))
+            )
+        );
+        let back = rbs::Value::from_value(&value).unwrap().as_value().unwrap();
+        assert_eq!(
+            format!("{:?}", back.p("/created")),
+            format!("{:?}", value.p("/created"))
+        );
+    }
+
+    #[test]
+    fn test_decimal() {
+        let amount = Decimal::from_str("12345678901234567890.12").unwrap();
+        let value = Value::Object(BTreeMap::from([
+            ("amount".to_string(), Value::Decimal(amount)),
+            ("price".to_string(), Value::F64(0.1)),
+        ]));
+
+        let json = serde_json::Value::from_value(&value).unwrap();
+        assert_eq!(json["amount"], serde_json::json!("12345678901234567890.12"));
+        let res = json.as_value().unwrap();
+        assert_eq!(res.p("/amount").unwrap().as_decimal().unwrap(), amount);
+        assert_eq!(
+            res.p("/price").unwrap().as_decimal().unwrap().to_string(),
+            "0.1"
+        );
+
+        let yaml = serde_yaml::Value::from_value(&value).unwrap();
+        let res = yaml.as_value().unwrap();
+        assert_eq!(res.p("/amount").unwrap().as_decimal().unwrap(), amount);
+
+        let rbs = rbs::Value::from_value(&value).unwrap();
+        let res = rbs.as_value().unwrap();
+        assert!(matches!(res.p("/amount"), Some(Value::Decimal(v)) if *v == amount));
+
+        assert_eq!(
+            rbs::to_value(&value).unwrap()["amount"],
+            rbs::Value::String("12345678901234567890.12".to_string())
+        );
+
+        let res = from_cbor(&to_cbor(&value).unwrap()).unwrap();
+        assert!(matches!(res.p("/amount"), Some(Value::Decimal(v)) if *v == amount));
+        // 十进制小数标签：[2, 15]即1500，指数超出Decimal精度范围时返回错误
+        let res = from_cbor(&[0xc4, 0x82, 0x02, 0x0f]).unwrap();
+        assert_eq!(res.as_decimal().unwrap().to_string(), "1500");
+        let huge = [
+            0xc4, 0x82, 0x1b, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00,
+        ];
+        assert!(from_cbor(&huge).is_err());
+
+        let typed = serde_json::Value::from_typed_value(&value).unwrap();
+        assert_eq!(
+            typed["amount"],
+            serde_json::json!({"$decimal": "12345678901234567890.12"})
+        );
+        let res = typed.as_typed_value().unwrap();
+        assert_eq!(format!("{:?}", res), format!("{:?}", value));
+
+        #[derive(AsValue, FromValue, MergeValue, Clone, Default, Debug)]
+        struct Account {
+            balance: Decimal,
+        }
+        let mut account = Account::default();
+        account
+            .merge_value(Some(&object(vec![(
+                "balance",
+                Value::String("99.90".to_string()),
+            )])))
+            .unwrap();
+        assert_eq!(account.balance.to_string(), "99.90");
+        let res = account.as_value().unwrap();
+        assert!(matches!(res.p("/balance"), Some(Value::Decimal(_))));
+        assert_eq!(Account::from_value(&res).unwrap().balance, account.balance);
+    }
+}
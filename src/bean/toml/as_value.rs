@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::AsValueTrait,
+    date::{Date, DateTime, Time},
+    error::ERR_CONVERT,
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
+
+impl AsValueTrait for toml::Value {
+    fn as_value(&self) -> Result<Value> {
+        match self {
+            toml::Value::String(v) => OK(Value::String(v.clone())),
+            toml::Value::Integer(v) => OK(Value::I64(*v)),
+            toml::Value::Float(v) => OK(Value::F64(*v)),
+            toml::Value::Boolean(v) => OK(Value::Bool(*v)),
+            toml::Value::Datetime(v) => datetime_as_value(v.to_string().as_str()),
+            toml::Value::Array(v) => v
+                .iter()
+                .map(|x| x.as_value())
+                .collect_into_vec()
+                .map(Value::Array),
+            toml::Value::Table(o) => {
+                let mut map = BTreeMap::new();
+                for (k, v) in o {
+                    map.insert(k.to_string(), v.as_value()?);
+                }
+                OK(Value::Object(map))
+            }
+        }
+    }
+}
+
+/// Toml日期转换为内置日期类型，带有时区的日期时间转换为UTC时间
+fn datetime_as_value(s: &str) -> Result<Value> {
+    let res = if s.find(':') == Some(2) {
+        chrono::NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+            .map(|x| Time::from_time(x).map(Value::Time))
+    } else if s.len() == 10 {
+        chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(|x| Date::from_date(x).map(Value::Date))
+    } else if s.ends_with('Z') || s.get(19..).unwrap_or("").contains(['+', '-']) {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|x| DateTime::from_datetime(x.naive_utc()).map(Value::DateTime))
+    } else {
+        chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+            .map(|x| DateTime::from_datetime(x).map(Value::DateTime))
+    };
+    res.map_err(|e| {
+        ERR_CONVERT
+            .msg_detail(format!("Toml日期[{}]转换为内置Value对象失败", s).as_str())
+            .cause(e)
+    })?
+}
@@ -0,0 +1,60 @@
+use crate::{
+    bean::FromValueTrait, error::ERR_INTERNAL, iter::CollectResultTrait, Result, Value, OK,
+};
+
+impl FromValueTrait for toml::Value {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Null => Err(ERR_INTERNAL.msg_detail("Toml对象不支持Null类型")),
+            Value::Bool(v) => OK(toml::Value::Boolean(*v)),
+            Value::I32(v) => OK(toml::Value::Integer(*v as i64)),
+            Value::I64(v) => OK(toml::Value::Integer(*v)),
+            Value::U32(v) => OK(toml::Value::Integer(*v as i64)),
+            Value::U64(v) => i64::try_from(*v).map(toml::Value::Integer).map_err(|_| {
+                ERR_INTERNAL.msg_detail(format!("数值[{}]超出Toml整数的范围", v).as_str())
+            }),
+            Value::F32(v) => OK(toml::Value::Float(*v as f64)),
+            Value::F64(v) => OK(toml::Value::Float(*v)),
+            Value::Decimal(v) => OK(toml::Value::String(v.to_string())),
+            Value::Date(v) => datetime(v.to_string().as_str()),
+            Value::Time(v) => datetime(v.to_string().as_str()),
+            Value::DateTime(v) => datetime(
+                v.to_chrono_datetime()
+                    .format("%Y-%m-%dT%H:%M:%S")
+                    .to_string()
+                    .as_str(),
+            ),
+            Value::YearMonth(v) => OK(toml::Value::String(v.to_string())),
+            Value::String(v) => OK(toml::Value::String(v.clone())),
+            Value::Binary(_v) => {
+                Err(ERR_INTERNAL.msg_detail("不支持从内置Value对象转换Binary类型到Toml对象"))
+            }
+            Value::Array(arr) => arr
+                .iter()
+                .map(Self::from_value)
+                .collect_into_vec()
+                .map(toml::Value::Array),
+            Value::Object(obj) => {
+                let mut map = toml::value::Table::new();
+                for (k, v) in obj {
+                    // Toml中没有Null类型，值为Null的键直接忽略
+                    if let Value::Null = v {
+                        continue;
+                    }
+                    map.insert(k.to_string(), Self::from_value(v)?);
+                }
+                OK(toml::Value::Table(map))
+            }
+        }
+    }
+}
+
+fn datetime(s: &str) -> Result<toml::Value> {
+    s.parse::<toml::value::Datetime>()
+        .map(toml::Value::Datetime)
+        .map_err(|e| {
+            ERR_INTERNAL
+                .msg_detail(format!("日期[{}]转换为Toml日期失败", s).as_str())
+                .cause(e)
+        })
+}
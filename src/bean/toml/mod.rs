@@ -0,0 +1,3 @@
+mod as_value;
+mod from_value;
+mod pointer;
@@ -0,0 +1,85 @@
+use crate::{
+    bean::{
+        base::{
+            parse_index, parse_pointer, pointer_get_mut, pointer_remove, pointer_set, PointerNode,
+        },
+        PointerMutTrait, PointerTrait,
+    },
+    Result,
+};
+
+impl PointerTrait for toml::Value {
+    type Context = toml::Value;
+    fn p(&self, pointer: &str) -> Option<&Self::Context> {
+        parse_pointer(pointer)
+            .ok()?
+            .into_iter()
+            .try_fold(self, |target, token| match target {
+                toml::Value::Table(map) => map.get(&token),
+                toml::Value::Array(list) => parse_index(&token).and_then(|x| list.get(x)),
+                _ => None,
+            })
+    }
+}
+
+impl PointerMutTrait for toml::Value {
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context> {
+        pointer_get_mut(self, pointer)
+    }
+
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>> {
+        pointer_set(self, pointer, value)
+    }
+
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>> {
+        pointer_remove(self, pointer)
+    }
+}
+
+/// Toml中没有Null类型，路径中缺失的层级会按下一层级的键直接创建为数组或Table
+impl PointerNode for toml::Value {
+    fn new_array() -> Self {
+        toml::Value::Array(vec![])
+    }
+
+    fn new_object() -> Self {
+        toml::Value::Table(toml::value::Table::new())
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_object(&self) -> bool {
+        self.is_table()
+    }
+
+    fn list(&self) -> Option<&Vec<Self>> {
+        self.as_array()
+    }
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.as_array_mut()
+    }
+
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        self.as_table().and_then(|x| x.get(key))
+    }
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self> {
+        self.as_table_mut().and_then(|x| x.get_mut(key))
+    }
+
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self> {
+        self.as_table_mut()
+            .and_then(|x| x.insert(key.to_string(), value))
+    }
+
+    fn remove_key(&mut self, key: &str) -> Option<Self> {
+        self.as_table_mut().and_then(|x| x.remove(key))
+    }
+}
@@ -0,0 +1,122 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{
+    date::{Date, DateTime, Time, YearMonth},
+    error::ERR_CONVERT,
+    Result, Value, OK,
+};
+
+/// 类型标记，Json中以`$`为前缀作为单键Object的键，Yaml中作为Tag使用
+pub(super) const TAG_BINARY: &str = "binary";
+pub(super) const TAG_DATE: &str = "date";
+pub(super) const TAG_TIME: &str = "time";
+pub(super) const TAG_DATETIME: &str = "datetime";
+pub(super) const TAG_YEARMONTH: &str = "yearmonth";
+pub(super) const TAG_I32: &str = "i32";
+pub(super) const TAG_U32: &str = "u32";
+pub(super) const TAG_U64: &str = "u64";
+pub(super) const TAG_F32: &str = "f32";
+pub(super) const TAG_F64: &str = "f64";
+pub(super) const TAG_DECIMAL: &str = "decimal";
+/// 仅用于Json，原始数据为单键且键名与类型标记冲突的Object
+pub(super) const TAG_OBJECT: &str = "object";
+
+const TAGS: [&str; 12] = [
+    TAG_BINARY,
+    TAG_DATE,
+    TAG_TIME,
+    TAG_DATETIME,
+    TAG_YEARMONTH,
+    TAG_I32,
+    TAG_U32,
+    TAG_U64,
+    TAG_F32,
+    TAG_F64,
+    TAG_DECIMAL,
+    TAG_OBJECT,
+];
+
+/// 检查是否为类型标记
+pub(super) fn is_tag(tag: &str) -> bool {
+    TAGS.contains(&tag)
+}
+
+/// 获取需附加类型标记的数据对应的标记及标记后的数据
+///
+/// Json及Yaml无法区分的整数、浮点数位宽同样需要标记，I64及有限的F64无需标记
+pub(super) fn encode_typed(value: &Value) -> Option<(&'static str, Value)> {
+    match value {
+        Value::Binary(v) => Some((TAG_BINARY, Value::String(STANDARD.encode(v)))),
+        Value::Date(v) => Some((TAG_DATE, Value::String(v.to_string()))),
+        Value::Time(v) => Some((TAG_TIME, Value::String(v.to_string()))),
+        Value::DateTime(v) => Some((TAG_DATETIME, Value::String(v.to_string()))),
+        Value::YearMonth(v) => Some((TAG_YEARMONTH, Value::String(v.to_string()))),
+        Value::I32(v) => Some((TAG_I32, Value::I64(*v as i64))),
+        Value::U32(v) => Some((TAG_U32, Value::I64(*v as i64))),
+        Value::U64(v) => Some((TAG_U64, Value::U64(*v))),
+        Value::F32(v) => Some((TAG_F32, encode_float(*v as f64))),
+        Value::F64(v) if !v.is_finite() => Some((TAG_F64, encode_float(*v))),
+        Value::Decimal(v) => Some((TAG_DECIMAL, Value::String(v.to_string()))),
+        _ => None,
+    }
+}
+
+/// 按类型标记还原数据，value为标记后的数据
+pub(super) fn decode_typed(tag: &str, value: &Value) -> Result<Value> {
+    let res = match (tag, value) {
+        (TAG_BINARY, Value::String(s)) => STANDARD.decode(s).ok().map(Value::Binary),
+        (TAG_DATE, Value::String(s)) => Date::parse_str(s).ok().map(Value::Date),
+        (TAG_TIME, Value::String(s)) => Time::parse_str(s).ok().map(Value::Time),
+        (TAG_DATETIME, Value::String(s)) => DateTime::parse_str(s).ok().map(Value::DateTime),
+        (TAG_YEARMONTH, Value::String(s)) => YearMonth::parse_str(s).ok().map(Value::YearMonth),
+        (TAG_I32, v) => integer(v)
+            .and_then(|x| i32::try_from(x).ok())
+            .map(Value::I32),
+        (TAG_U32, v) => integer(v)
+            .and_then(|x| u32::try_from(x).ok())
+            .map(Value::U32),
+        (TAG_U64, v) => integer(v)
+            .and_then(|x| u64::try_from(x).ok())
+            .map(Value::U64),
+        (TAG_F32, v) => decode_float(v).map(|x| Value::F32(x as f32)),
+        (TAG_F64, v) => decode_float(v).map(Value::F64),
+        (TAG_DECIMAL, v) => v.as_decimal().ok().map(Value::Decimal),
+        _ => None,
+    };
+    res.map(OK).unwrap_or_else(|| {
+        Err(ERR_CONVERT
+            .msg_detail(format!("类型标记[{}]对应的数据[{:?}]不合法", tag, value).as_str()))
+    })
+}
+
+fn integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::I64(v) => Some(*v as i128),
+        Value::U64(v) => Some(*v as i128),
+        _ => None,
+    }
+}
+
+fn encode_float(v: f64) -> Value {
+    if v.is_nan() {
+        Value::String("NaN".to_string())
+    } else if v.is_infinite() && v > 0.0 {
+        Value::String("inf".to_string())
+    } else if v.is_infinite() {
+        Value::String("-inf".to_string())
+    } else {
+        Value::F64(v)
+    }
+}
+
+fn decode_float(value: &Value) -> Option<f64> {
+    match value {
+        Value::F64(v) => Some(*v),
+        Value::I64(v) => Some(*v as f64),
+        Value::U64(v) => Some(*v as f64),
+        Value::String(s) if s == "NaN" => Some(f64::NAN),
+        Value::String(s) if s == "inf" => Some(f64::INFINITY),
+        Value::String(s) if s == "-inf" => Some(f64::NEG_INFINITY),
+        _ => None,
+    }
+}
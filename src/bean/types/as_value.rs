@@ -1,3 +1,5 @@
+use rust_decimal::Decimal;
+
 use crate::{bean::AsValueTrait, iter::CollectResultTrait, Result, Value, OK};
 
 impl<T> AsValueTrait for Option<T>
@@ -66,6 +68,12 @@ impl AsValueTrait for usize {
     }
 }
 
+impl AsValueTrait for Value {
+    fn as_value(&self) -> Result<Value> {
+        OK(self.clone())
+    }
+}
+
 impl AsValueTrait for i32 {
     fn as_value(&self) -> Result<Value> {
         OK(Value::I32(*self))
@@ -78,14 +86,32 @@ impl AsValueTrait for i64 {
     }
 }
 
+impl AsValueTrait for u32 {
+    fn as_value(&self) -> Result<Value> {
+        OK(Value::U32(*self))
+    }
+}
+
 impl AsValueTrait for u64 {
     fn as_value(&self) -> Result<Value> {
         OK(Value::U64(*self))
     }
 }
 
+impl AsValueTrait for f32 {
+    fn as_value(&self) -> Result<Value> {
+        OK(Value::F32(*self))
+    }
+}
+
 impl AsValueTrait for f64 {
     fn as_value(&self) -> Result<Value> {
         OK(Value::F64(*self))
     }
 }
+
+impl AsValueTrait for Decimal {
+    fn as_value(&self) -> Result<Value> {
+        OK(Value::Decimal(*self))
+    }
+}
@@ -0,0 +1,93 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    bean::{field_path_error, FromValueTrait},
+    types::{DoubleExt, IntegerExt},
+    Result, Value, OK,
+};
+
+impl<T> FromValueTrait for Option<T>
+where
+    T: FromValueTrait,
+{
+    fn from_value(value: &Value) -> Result<Self> {
+        if value.is_null()? {
+            OK(None)
+        } else {
+            T::from_value(value).map(Some)
+        }
+    }
+}
+
+impl<T> FromValueTrait for Vec<T>
+where
+    T: FromValueTrait,
+{
+    fn from_value(value: &Value) -> Result<Self> {
+        let mut res = vec![];
+        for (i, v) in value.as_array()?.iter().enumerate() {
+            res.push(T::from_value(v).map_err(|e| field_path_error(e, i.to_string().as_str()))?);
+        }
+        OK(res)
+    }
+}
+
+impl FromValueTrait for Value {
+    fn from_value(value: &Value) -> Result<Self> {
+        OK(value.clone())
+    }
+}
+
+impl FromValueTrait for bool {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_bool()
+    }
+}
+
+impl FromValueTrait for String {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_str().map(|x| x.to_string())
+    }
+}
+
+impl FromValueTrait for i32 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_i32()
+    }
+}
+
+impl FromValueTrait for i64 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_i64()
+    }
+}
+
+impl FromValueTrait for u32 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_u64()?.cast_to_u32()
+    }
+}
+
+impl FromValueTrait for u64 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_u64()
+    }
+}
+
+impl FromValueTrait for f32 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_f64()?.cast_to_f32()
+    }
+}
+
+impl FromValueTrait for f64 {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_f64()
+    }
+}
+
+impl FromValueTrait for Decimal {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_decimal()
+    }
+}
@@ -1,3 +1,5 @@
+use rust_decimal::Decimal;
+
 use crate::{bean::MergeValueTrait, Result, Value, OK};
 
 impl<T> MergeValueTrait for Option<T>
@@ -97,6 +99,17 @@ impl MergeValueTrait for f64 {
     }
 }
 
+impl MergeValueTrait for Decimal {
+    fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
+        if let Some(v) = target {
+            if !v.is_null()? {
+                *self = v.as_decimal()?;
+            }
+        }
+        OK(*self)
+    }
+}
+
 impl MergeValueTrait for String {
     fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
         if let Some(v) = target {
@@ -1,2 +1,3 @@
 mod as_value;
+mod from_value;
 mod merge_value;
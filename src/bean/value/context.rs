@@ -5,7 +5,7 @@ impl ContextTrait for Value {
 
     fn get_value(&self, key: &str) -> Result<Option<&Value>> {
         if self.is_object() {
-            let map = self.as_object_mut()?;
+            let map = self.as_object()?;
             OK(map.get(key))
         } else {
             OK(None)
@@ -1,34 +1,127 @@
-use crate::{bean::MergeTrait, error::ERR_MERGE, Result, Value, OK};
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::{merge_options::push_path, ArrayStrategy, MergeOptions, MergeTrait},
+    error::ERR_MERGE,
+    Result, Value, OK,
+};
 
 impl MergeTrait for Value {
     fn merge_self(&mut self, target: &Self) -> Result<Self> {
-        match self {
-            Value::Array(arr) => {
-                match target {
+        self.merge_with(target, &MergeOptions::default())
+    }
+
+    fn merge_with(&mut self, target: &Self, options: &MergeOptions) -> Result<Self> {
+        merge(self, target, options, &[])?;
+        OK(self.clone())
+    }
+}
+
+/// 按合并选项将target合并至source中，path为source所在的路径
+pub(crate) fn merge(
+    source: &mut Value,
+    target: &Value,
+    options: &MergeOptions,
+    path: &[String],
+) -> Result<()> {
+    match source {
+        Value::Array(arr) => {
+            match options.array_strategy_at(path) {
+                ArrayStrategy::Replace => *source = replacement(target, options),
+                ArrayStrategy::Append => match target {
                     Value::Array(arr2) => {
-                        arr.extend_from_slice(arr2);
+                        arr.extend(arr2.iter().map(|x| replacement(x, options)));
                     }
-                    v => {
-                        arr.push(v.clone());
+                    v => arr.push(replacement(v, options)),
+                },
+                ArrayStrategy::Union => {
+                    let items = match target {
+                        Value::Array(arr2) => arr2.iter().collect(),
+                        v => vec![v],
+                    };
+                    for item in items {
+                        if !arr.iter().any(|x| x == item) {
+                            arr.push(replacement(item, options));
+                        }
                     }
-                };
-                OK(self.clone())
-            }
-            Value::Object(obj) => match target {
-                Value::Object(obj2) => {
-                    for (k2, v2) in obj2 {
-                        if obj.contains_key(k2) {
-                            let v2 = obj.get_mut(k2).unwrap().merge_self(v2)?.clone();
-                            obj.insert(k2.to_string(), v2);
-                        } else {
-                            obj.insert(k2.to_string(), v2.clone());
+                }
+                ArrayStrategy::MergeByKey(key) => {
+                    let items = match target {
+                        Value::Array(arr2) => arr2.iter().collect(),
+                        v => vec![v],
+                    };
+                    for item in items {
+                        let index =
+                            item.as_object()
+                                .ok()
+                                .and_then(|obj| obj.get(key))
+                                .and_then(|k| {
+                                    arr.iter().position(|x| {
+                                        x.as_object()
+                                            .ok()
+                                            .and_then(|obj| obj.get(key))
+                                            .map(|k2| k == k2)
+                                            .unwrap_or(false)
+                                    })
+                                });
+                        match index {
+                            Some(i) => merge(
+                                &mut arr[i],
+                                item,
+                                options,
+                                &push_path(path, i.to_string().as_str()),
+                            )?,
+                            None => arr.push(replacement(item, options)),
                         }
                     }
-                    OK(self.clone())
                 }
-                _ => Err(ERR_MERGE.msg_detail("Value之Object数据不接受来自于其它类型的MERGE操作")),
-            },
-            _ => OK(target.clone()),
+            };
+            OK(())
+        }
+        Value::Object(obj) => match target {
+            Value::Object(obj2) => {
+                for (k2, v2) in obj2 {
+                    if options.is_null_as_delete() && matches!(v2, Value::Null) {
+                        obj.remove(k2);
+                    } else if let Some(v) = obj.get_mut(k2) {
+                        merge(v, v2, options, &push_path(path, k2))?;
+                    } else {
+                        obj.insert(k2.to_string(), replacement(v2, options));
+                    }
+                }
+                OK(())
+            }
+            _ if options.is_replace_on_mismatch() => {
+                *source = replacement(target, options);
+                OK(())
+            }
+            _ => Err(ERR_MERGE
+                .msg_detail("Value之Object数据不接受来自于其它类型的MERGE操作")
+                .context_value("pointer".to_string(), Value::String(to_pointer(path)))),
+        },
+        _ => {
+            *source = replacement(target, options);
+            OK(())
         }
     }
 }
+
+/// 获取用于替换原有对象的数据，null表示删除时需移除目标Object中值为null的键
+fn replacement(target: &Value, options: &MergeOptions) -> Value {
+    match target {
+        Value::Object(obj) if options.is_null_as_delete() => {
+            let mut map = BTreeMap::new();
+            for (k, v) in obj {
+                if !matches!(v, Value::Null) {
+                    map.insert(k.to_string(), replacement(v, options));
+                }
+            }
+            Value::Object(map)
+        }
+        v => v.clone(),
+    }
+}
+
+fn to_pointer(path: &[String]) -> String {
+    path.iter().map(|x| format!("/{}", x)).collect()
+}
@@ -1,3 +1,5 @@
 mod context;
 mod merge;
 mod pointer;
+
+pub(super) use merge::merge;
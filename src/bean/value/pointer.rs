@@ -1,6 +1,11 @@
+use std::collections::BTreeMap;
+
 use crate::{
-    bean::{base::parse_index, PointerTrait},
-    Value,
+    bean::{
+        base::{parse_index, pointer_get_mut, pointer_remove, pointer_set, PointerNode},
+        PointerMutTrait, PointerTrait,
+    },
+    Result, Value,
 };
 
 impl PointerTrait for Value {
@@ -23,3 +28,81 @@ impl PointerTrait for Value {
             })
     }
 }
+
+impl PointerMutTrait for Value {
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context> {
+        pointer_get_mut(self, pointer)
+    }
+
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>> {
+        pointer_set(self, pointer, value)
+    }
+
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>> {
+        pointer_remove(self, pointer)
+    }
+}
+
+impl PointerNode for Value {
+    fn new_array() -> Self {
+        Value::Array(vec![])
+    }
+
+    fn new_object() -> Self {
+        Value::Object(BTreeMap::new())
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    fn is_object(&self) -> bool {
+        matches!(self, Value::Object(_))
+    }
+
+    fn list(&self) -> Option<&Vec<Self>> {
+        match self {
+            Value::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>> {
+        match self {
+            Value::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        match self {
+            Value::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self> {
+        match self {
+            Value::Object(map) => map.get_mut(key),
+            _ => None,
+        }
+    }
+
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self> {
+        match self {
+            Value::Object(map) => map.insert(key.to_string(), value),
+            _ => None,
+        }
+    }
+
+    fn remove_key(&mut self, key: &str) -> Option<Self> {
+        match self {
+            Value::Object(map) => map.remove(key),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use std::collections::BTreeMap;
 
-use crate::{bean::AsValueTrait, error::ERR_INTERNAL, iter::CollectResultTrait, Result, Value, OK};
+use crate::{
+    bean::AsValueTrait,
+    error::{ERR_CONVERT, ERR_INTERNAL},
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
+
+use super::tag::{insert_entry, resolve_tag};
 
 impl AsValueTrait for serde_yaml::Value {
     fn as_value(&self) -> Result<Value> {
@@ -25,13 +32,34 @@ impl AsValueTrait for serde_yaml::Value {
                 .collect_into_vec()
                 .map(Value::Array),
             serde_yaml::Value::Mapping(o) => {
+                if let Some(merged) = expand_merge(self)? {
+                    return merged.as_value();
+                }
                 let mut map = BTreeMap::new();
                 for (k, v) in o {
-                    map.insert(k.as_str().unwrap().to_string(), v.as_value().unwrap());
+                    insert_entry(&mut map, k, v.as_value()?)?;
                 }
                 OK(Value::Object(map))
             }
-            serde_yaml::Value::Tagged(_) => Err(ERR_INTERNAL.msg_detail("暂不支持Yaml使用Tag类型")),
+            serde_yaml::Value::Tagged(v) => resolve_tag(v),
+        }
+    }
+
+    fn as_typed_value(&self) -> Result<Value> {
+        super::typed::as_typed_value(self)
+    }
+}
+
+/// 存在<<合并键时展开锚点引用的内容，不存在时返回None
+pub(super) fn expand_merge(value: &serde_yaml::Value) -> Result<Option<serde_yaml::Value>> {
+    match value {
+        serde_yaml::Value::Mapping(o) if o.contains_key("<<") => {
+            let mut merged = value.clone();
+            merged
+                .apply_merge()
+                .map_err(|e| ERR_CONVERT.msg_detail("Yaml合并键<<展开失败").cause(e))?;
+            OK(Some(merged))
         }
+        _ => OK(None),
     }
 }
@@ -18,6 +18,7 @@ impl FromValueTrait for serde_yaml::Value {
                 (*v).cast_to_f64().unwrap(),
             ))),
             Value::F64(v) => OK(serde_yaml::Value::Number(serde_yaml::Number::from(*v))),
+            Value::Decimal(v) => OK(serde_yaml::Value::String(v.to_string())),
             Value::Date(v) => OK(serde_yaml::Value::String(v.to_string())),
             Value::Time(v) => OK(serde_yaml::Value::String(v.to_string())),
             Value::DateTime(v) => OK(serde_yaml::Value::String(v.to_string())),
@@ -45,4 +46,8 @@ impl FromValueTrait for serde_yaml::Value {
             }
         }
     }
+
+    fn from_typed_value(value: &Value) -> Result<Self> {
+        super::typed::from_typed_value(value)
+    }
 }
@@ -1,3 +1,7 @@
 mod as_value;
 mod from_value;
 mod pointer;
+mod tag;
+mod typed;
+
+pub use tag::{register_yaml_tag, YamlTagResolver};
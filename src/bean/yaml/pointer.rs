@@ -1,4 +1,10 @@
-use crate::bean::{base::parse_index, PointerTrait};
+use crate::{
+    bean::{
+        base::{parse_index, pointer_get_mut, pointer_remove, pointer_set, PointerNode},
+        PointerMutTrait, PointerTrait,
+    },
+    Result,
+};
 
 impl PointerTrait for serde_yaml::Value {
     type Context = serde_yaml::Value;
@@ -20,3 +26,64 @@ impl PointerTrait for serde_yaml::Value {
             })
     }
 }
+
+impl PointerMutTrait for serde_yaml::Value {
+    fn p_mut(&mut self, pointer: &str) -> Option<&mut Self::Context> {
+        pointer_get_mut(self, pointer)
+    }
+
+    fn set_by_pointer(
+        &mut self,
+        pointer: &str,
+        value: Self::Context,
+    ) -> Result<Option<Self::Context>> {
+        pointer_set(self, pointer, value)
+    }
+
+    fn remove_by_pointer(&mut self, pointer: &str) -> Result<Option<Self::Context>> {
+        pointer_remove(self, pointer)
+    }
+}
+
+impl PointerNode for serde_yaml::Value {
+    fn new_array() -> Self {
+        serde_yaml::Value::Sequence(vec![])
+    }
+
+    fn new_object() -> Self {
+        serde_yaml::Value::Mapping(serde_yaml::Mapping::new())
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_null()
+    }
+
+    fn is_object(&self) -> bool {
+        self.is_mapping()
+    }
+
+    fn list(&self) -> Option<&Vec<Self>> {
+        self.as_sequence()
+    }
+
+    fn list_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.as_sequence_mut()
+    }
+
+    fn get_key(&self, key: &str) -> Option<&Self> {
+        self.as_mapping().and_then(|x| x.get(key))
+    }
+
+    fn get_key_mut(&mut self, key: &str) -> Option<&mut Self> {
+        self.as_mapping_mut().and_then(|x| x.get_mut(key))
+    }
+
+    fn insert_key(&mut self, key: &str, value: Self) -> Option<Self> {
+        self.as_mapping_mut()
+            .and_then(|x| x.insert(serde_yaml::Value::String(key.to_string()), value))
+    }
+
+    fn remove_key(&mut self, key: &str) -> Option<Self> {
+        self.as_mapping_mut().and_then(|x| x.remove(key))
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+
+use crate::{
+    bean::{
+        typed::{decode_typed, TAG_BINARY, TAG_DATE, TAG_DATETIME, TAG_TIME, TAG_YEARMONTH},
+        AsValueTrait,
+    },
+    error::{AppError, ERR_CONVERT, ERR_DATA, ERR_ENV_VAR, ERR_INTERNAL},
+    Result, Value, OK,
+};
+
+/// Yaml Tag解析器，参数为Tag所标记的数据
+pub type YamlTagResolver = Arc<dyn Fn(&serde_yaml::Value) -> Result<Value> + Send + Sync>;
+
+lazy_static! {
+    static ref RESOLVERS: RwLock<HashMap<String, YamlTagResolver>> = RwLock::new(HashMap::new());
+}
+
+/// 注册自定义的Yaml Tag解析器，tag不需要包含前缀`!`
+///
+/// 已注册的Tag会覆盖内置的同名Tag，内置支持以下Tag：
+/// - `!date 2022-10-01`、`!time 12:30:00`、`!datetime 2022-10-01 12:30:00`、`!yearmonth 2022-10`
+/// - `!binary AAH/`：base64编码的二进制数据
+/// - `!env VAR_NAME`：读取环境变量，环境变量不存在时返回ERR_ENV_VAR异常
+pub fn register_yaml_tag<F>(tag: &str, resolver: F) -> Result<()>
+where
+    F: Fn(&serde_yaml::Value) -> Result<Value> + Send + Sync + 'static,
+{
+    RESOLVERS
+        .write()
+        .map_err(|_| poisoned())?
+        .insert(tag.trim_start_matches('!').to_string(), Arc::new(resolver));
+    OK(())
+}
+
+/// 解析带有Tag的Yaml数据
+pub(super) fn resolve_tag(tagged: &serde_yaml::value::TaggedValue) -> Result<Value> {
+    let tag = tagged.tag.to_string();
+    let tag = tag.trim_start_matches('!');
+    let resolver = RESOLVERS.read().map_err(|_| poisoned())?.get(tag).cloned();
+    if let Some(f) = resolver {
+        return f(&tagged.value);
+    }
+    let text = match &tagged.value {
+        serde_yaml::Value::String(s) => s.as_str(),
+        _ => return Err(unsupported(tag)),
+    };
+    match tag {
+        TAG_DATE | TAG_TIME | TAG_YEARMONTH => decode_typed(tag, &Value::String(text.to_string())),
+        TAG_DATETIME => decode_typed(tag, &Value::String(text.replacen('T', " ", 1))),
+        TAG_BINARY => {
+            let s: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+            decode_typed(tag, &Value::String(s))
+        }
+        "env" => std::env::var(text).map(Value::String).map_err(|e| {
+            ERR_ENV_VAR
+                .msg_detail(format!("读取Yaml Tag[!env]中的环境变量[{}]失败", text).as_str())
+                .context_value("env".to_string(), Value::String(text.to_string()))
+                .cause(e)
+        }),
+        _ => Err(unsupported(tag)),
+    }
+}
+
+/// 将Yaml中Mapping的数据写入Object，不同的键转换为相同的字符串时返回ERR_DATA异常
+pub(super) fn insert_entry(
+    map: &mut BTreeMap<String, Value>,
+    key: &serde_yaml::Value,
+    value: Value,
+) -> Result<()> {
+    let name = key_to_string(key)?;
+    if map.contains_key(&name) {
+        return Err(ERR_DATA
+            .msg_detail(format!("Yaml对象的键[{:?}]转换为字符串后与其他键重复", key).as_str()));
+    }
+    map.insert(name, value);
+    OK(())
+}
+
+/// 将Yaml中Mapping的键转换为字符串
+///
+/// 布尔、数值及Null转换为其Yaml中的字面值，数组及Object转换为Json格式字符串
+fn key_to_string(key: &serde_yaml::Value) -> Result<String> {
+    match key {
+        serde_yaml::Value::String(v) => OK(v.clone()),
+        serde_yaml::Value::Bool(v) => OK(v.to_string()),
+        serde_yaml::Value::Number(v) => OK(v.to_string()),
+        serde_yaml::Value::Null => OK("null".to_string()),
+        v => match v.as_value()? {
+            Value::String(s) => OK(s),
+            value => serde_json::to_string(&value).map_err(|e| {
+                ERR_CONVERT
+                    .msg_detail(format!("Yaml对象的键[{:?}]转换为字符串失败", key).as_str())
+                    .cause(e)
+            }),
+        },
+    }
+}
+
+fn poisoned() -> AppError {
+    ERR_INTERNAL.msg_detail("Yaml Tag解析器的注册信息不可用")
+}
+
+fn unsupported(tag: &str) -> AppError {
+    ERR_CONVERT.msg_detail(
+        format!(
+            "不支持的Yaml Tag[!{}]，可通过register_yaml_tag注册自定义的Tag解析器",
+            tag
+        )
+        .as_str(),
+    )
+}
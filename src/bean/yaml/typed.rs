@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::{
+        typed::{decode_typed, encode_typed, is_tag, TAG_OBJECT},
+        AsValueTrait, FromValueTrait,
+    },
+    iter::CollectResultTrait,
+    Result, Value, OK,
+};
+
+use super::{as_value::expand_merge, tag::insert_entry};
+
+pub(super) fn as_typed_value(value: &serde_yaml::Value) -> Result<Value> {
+    match value {
+        serde_yaml::Value::Sequence(v) => v
+            .iter()
+            .map(as_typed_value)
+            .collect_into_vec()
+            .map(Value::Array),
+        serde_yaml::Value::Mapping(o) => {
+            if let Some(merged) = expand_merge(value)? {
+                return as_typed_value(&merged);
+            }
+            let mut map = BTreeMap::new();
+            for (k, v) in o {
+                insert_entry(&mut map, k, as_typed_value(v)?)?;
+            }
+            OK(Value::Object(map))
+        }
+        serde_yaml::Value::Tagged(v) => {
+            let tag = v.tag.to_string();
+            let tag = tag.trim_start_matches('!');
+            if is_tag(tag) && tag != TAG_OBJECT {
+                decode_typed(tag, &v.value.as_value()?)
+            } else {
+                value.as_value()
+            }
+        }
+        v => v.as_value(),
+    }
+}
+
+pub(super) fn from_typed_value(value: &Value) -> Result<serde_yaml::Value> {
+    if let Some((tag, v)) = encode_typed(value) {
+        return OK(serde_yaml::Value::Tagged(Box::new(
+            serde_yaml::value::TaggedValue {
+                tag: serde_yaml::value::Tag::new(tag),
+                value: serde_yaml::Value::from_value(&v)?,
+            },
+        )));
+    }
+    match value {
+        Value::Array(arr) => arr
+            .iter()
+            .map(from_typed_value)
+            .collect_into_vec()
+            .map(serde_yaml::Value::Sequence),
+        Value::Object(obj) => {
+            let mut map = serde_yaml::Mapping::new();
+            for (k, v) in obj {
+                map.insert(
+                    serde_yaml::Value::String(k.to_string()),
+                    from_typed_value(v)?,
+                );
+            }
+            OK(serde_yaml::Value::Mapping(map))
+        }
+        v => serde_yaml::Value::from_value(v),
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    bean::{
+        escape_token, key_to_pointer, ArrayStrategy, AsValueTrait, MergeOptions, MergeTrait,
+        PointerMutTrait, PointerTrait,
+    },
+    error::{AppError, ERR_ARGUMENT, ERR_ENV_VAR, ERR_PARSE},
+    Result, Value, OK,
+};
+
+use super::model::{Config, ConfigSource};
+
+/// 配置加载器
+///
+/// ```ignore
+/// let config = ConfigLoader::new()
+///     .defaults(defaults)
+///     .config_dir("./config")
+///     .profile("dev")
+///     .args(&matches, &[("port", "server.port")])?
+///     .load()?;
+/// let port = config.get_i64("server.port")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfigLoader {
+    defaults: Value,
+    config_dir: PathBuf,
+    profile: Option<String>,
+    env_prefix: String,
+    env_separator: String,
+    envs: Option<Vec<(String, String)>>,
+    args: Vec<(String, String, Value)>,
+    options: MergeOptions,
+}
+
+impl Default for ConfigLoader {
+    fn default() -> Self {
+        ConfigLoader {
+            defaults: Value::Object(BTreeMap::new()),
+            config_dir: PathBuf::from("."),
+            profile: None,
+            env_prefix: "KNIFE_".to_string(),
+            env_separator: "__".to_string(),
+            envs: None,
+            args: vec![],
+            options: MergeOptions::new().array_strategy(ArrayStrategy::Replace),
+        }
+    }
+}
+
+impl ConfigLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置默认配置
+    pub fn defaults(mut self, value: Value) -> Self {
+        self.defaults = value;
+        self
+    }
+
+    /// 设置配置文件所在目录，默认为当前目录
+    pub fn config_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.config_dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// 设置激活的profile，未设置时读取环境变量KNIFE_PROFILE
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// 设置环境变量前缀，默认为KNIFE_
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = prefix.to_string();
+        self
+    }
+
+    /// 设置环境变量中的层级分隔符，默认为双下划线
+    pub fn env_separator(mut self, separator: &str) -> Self {
+        self.env_separator = separator.to_string();
+        self
+    }
+
+    /// 使用指定的环境变量代替进程的环境变量
+    pub fn envs(mut self, envs: Vec<(String, String)>) -> Self {
+        self.envs = Some(envs);
+        self
+    }
+
+    /// 设置各层配置的合并选项，默认数组整体替换，类型不一致时返回异常
+    pub fn merge_options(mut self, options: MergeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 将命令行参数映射为配置项，mapping中为参数名称与配置项键的对应关系
+    ///
+    /// 仅在命令行中明确指定的参数会覆盖配置，参数的默认值将被忽略
+    pub fn args(mut self, matches: &clap::ArgMatches, mapping: &[(&str, &str)]) -> Result<Self> {
+        for (id, key) in mapping {
+            if matches.value_source(id) != Some(clap::ValueSource::CommandLine) {
+                continue;
+            }
+            let raw = matches.try_get_raw(id).map_err(|e| {
+                ERR_ARGUMENT
+                    .msg_detail(format!("命令行参数[{}]读取失败", id).as_str())
+                    .cause(e)
+            })?;
+            let mut values = vec![];
+            for v in raw.into_iter().flatten() {
+                match v.to_str() {
+                    Some(s) => values.push(parse_scalar(s)),
+                    None => {
+                        return Err(ERR_ARGUMENT
+                            .msg_detail(format!("命令行参数[{}]不是合法的UTF-8字符", id).as_str()))
+                    }
+                }
+            }
+            let value = match values.len() {
+                0 => Value::Bool(true),
+                1 => values.pop().unwrap(),
+                _ => Value::Array(values),
+            };
+            self.args.push((id.to_string(), key.to_string(), value));
+        }
+        OK(self)
+    }
+
+    /// 加载并合并所有配置
+    pub fn load(&self) -> Result<Config> {
+        let mut config = Config {
+            value: Value::Object(BTreeMap::new()),
+            sources: BTreeMap::new(),
+        };
+        merge_layer(
+            &mut config,
+            &self.defaults,
+            ConfigSource::Default,
+            &self.options,
+        )
+        .map_err(|e| rethrow(&ERR_PARSE, e, &ConfigSource::Default, None))?;
+
+        let mut envs = match &self.envs {
+            Some(v) => v.clone(),
+            None => std::env::vars_os()
+                .filter_map(|(k, v)| {
+                    let k = k.into_string().ok()?;
+                    if k.starts_with(self.env_prefix.as_str()) {
+                        Some((k, v))
+                    } else {
+                        None
+                    }
+                })
+                .map(|(k, v)| match v.into_string() {
+                    Ok(v) => OK((k, v)),
+                    Err(_) => Err(ERR_ENV_VAR
+                        .msg_detail(format!("环境变量[{}]不是合法的UTF-8字符", k).as_str())
+                        .context_value("env".to_string(), Value::String(k.clone()))),
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        envs.sort();
+
+        let profile_env = format!("{}PROFILE", self.env_prefix);
+        let profile = self.profile.clone().or_else(|| {
+            envs.iter()
+                .find(|(k, _)| *k == profile_env)
+                .map(|(_, v)| v.clone())
+        });
+        let mut names = vec!["application".to_string()];
+        if let Some(p) = profile {
+            names.push(format!("application-{}", p));
+        }
+        for name in names {
+            if let Some(path) = self.find_file(name.as_str()) {
+                let file = path.to_string_lossy().to_string();
+                let value = read_file(&path)?;
+                let source = ConfigSource::File(file);
+                merge_layer(&mut config, &value, source.clone(), &self.options)
+                    .map_err(|e| rethrow(&ERR_PARSE, e, &source, None))?;
+            }
+        }
+
+        for (k, v) in envs {
+            if k == profile_env || !k.starts_with(self.env_prefix.as_str()) {
+                continue;
+            }
+            let pointer: String = k[self.env_prefix.len()..]
+                .split(self.env_separator.as_str())
+                .map(|x| format!("/{}", escape_token(x.to_lowercase().as_str())))
+                .collect();
+            let source = ConfigSource::Env(k.clone());
+            let mut layer = Value::Object(BTreeMap::new());
+            layer
+                .set_by_pointer(pointer.as_str(), parse_scalar(v.as_str()))
+                .and_then(|_| merge_layer(&mut config, &layer, source.clone(), &self.options))
+                .map_err(|e| rethrow(&ERR_ENV_VAR, e, &source, Some(pointer.as_str())))?;
+        }
+
+        for (id, key, v) in &self.args {
+            let pointer = key_to_pointer(key);
+            let source = ConfigSource::CommandLine(id.to_string());
+            let mut layer = Value::Object(BTreeMap::new());
+            layer
+                .set_by_pointer(pointer.as_str(), v.clone())
+                .and_then(|_| merge_layer(&mut config, &layer, source.clone(), &self.options))
+                .map_err(|e| rethrow(&ERR_ARGUMENT, e, &source, Some(pointer.as_str())))?;
+        }
+        OK(config)
+    }
+
+    fn find_file(&self, name: &str) -> Option<PathBuf> {
+        ["yaml", "yml", "json"]
+            .iter()
+            .map(|ext| self.config_dir.join(format!("{}.{}", name, ext)))
+            .find(|x| x.is_file())
+    }
+}
+
+/// 读取配置文件，json扩展名按JSON格式解析，其余按YAML格式解析，YAML中可使用!env等Tag
+fn read_file(path: &Path) -> Result<Value> {
+    let file = path.to_string_lossy().to_string();
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AppError::from(e).context_value("file".to_string(), Value::String(file.clone()))
+    })?;
+    let res = if path.extension().map(|x| x == "json").unwrap_or(false) {
+        serde_json::from_str::<Value>(content.as_str()).map_err(|e| ERR_PARSE.cause(e))
+    } else {
+        serde_yaml::from_str::<serde_yaml::Value>(content.as_str())
+            .map_err(|e| ERR_PARSE.cause(e))
+            .and_then(|v| v.as_value())
+    };
+    let value = res.map_err(|e| {
+        e.msg_detail(format!("配置文件[{}]解析失败", file).as_str())
+            .context_value("file".to_string(), Value::String(file.clone()))
+    })?;
+    match value {
+        Value::Object(_) => OK(value),
+        Value::Null => OK(Value::Object(BTreeMap::new())),
+        _ => Err(ERR_PARSE
+            .msg_detail(format!("配置文件[{}]的根节点需为Object", file).as_str())
+            .context_value("file".to_string(), Value::String(file))
+            .context_value("pointer".to_string(), Value::String(String::new()))),
+    }
+}
+
+/// 合并一层配置并记录其中各配置项的来源
+fn merge_layer(
+    config: &mut Config,
+    layer: &Value,
+    source: ConfigSource,
+    options: &MergeOptions,
+) -> Result<()> {
+    config.value.merge_with(layer, options)?;
+    let mut pointers = vec![];
+    leaf_pointers(layer, String::new(), &mut pointers);
+    for pointer in pointers {
+        config.sources.insert(pointer, source.clone());
+    }
+    let value = &config.value;
+    config.sources.retain(|k, _| value.p(k.as_str()).is_some());
+    OK(())
+}
+
+/// 收集对象中所有叶子节点的路径，数组视为叶子节点
+fn leaf_pointers(value: &Value, pointer: String, res: &mut Vec<String>) {
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (k, v) in obj {
+                leaf_pointers(v, format!("{}/{}", pointer, escape_token(k)), res);
+            }
+        }
+        _ if !pointer.is_empty() => res.push(pointer),
+        _ => {}
+    }
+}
+
+/// 将合并配置时的异常转换为指定类型的异常，并记录配置来源及出错的路径，原异常作为错误原因
+///
+/// 合并异常中已记录路径时优先使用该路径，否则使用pointer参数
+fn rethrow(
+    base: &AppError,
+    err: AppError,
+    source: &ConfigSource,
+    pointer: Option<&str>,
+) -> AppError {
+    let detail = err.msg_detail_ref().unwrap_or_else(|| err.msg_ref());
+    let mut res = base.msg_detail(format!("配置[{}]合并失败：{}", source, detail).as_str());
+    let mut has_pointer = false;
+    if let Some(map) = err.context_map_ref() {
+        for (k, v) in map {
+            has_pointer |= k == "pointer";
+            res = res.context_value(k.to_string(), v.clone());
+        }
+    }
+    if let (false, Some(p)) = (has_pointer, pointer) {
+        res = res.context_value("pointer".to_string(), Value::String(p.to_string()));
+    }
+    if let ConfigSource::File(file) = source {
+        res = res.context_value("file".to_string(), Value::String(file.to_string()));
+    }
+    res.context_value("source".to_string(), Value::String(source.to_string()))
+        .cause(err)
+}
+
+/// 将环境变量及命令行参数中的字符串转换为布尔、整数、浮点数或字符串
+///
+/// 仅在转换后的数值可还原为原字符串时转换为数值，如`0123`、`1.10`均保留为字符串，避免丢失数据
+fn parse_scalar(s: &str) -> Value {
+    match s {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            if let Some(v) = s.parse::<i64>().ok().filter(|x| x.to_string() == s) {
+                Value::I64(v)
+            } else if let Some(v) = s
+                .parse::<f64>()
+                .ok()
+                .filter(|x| x.is_finite() && x.to_string() == s)
+            {
+                Value::F64(v)
+            } else {
+                Value::String(s.to_string())
+            }
+        }
+    }
+}
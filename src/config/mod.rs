@@ -0,0 +1,16 @@
+//! 分层配置工具类
+//!
+//! 按以下顺序加载配置并合并为一个内置Value对象，后加载的配置覆盖先加载的配置：
+//! 1. 程序中设置的默认配置
+//! 2. 配置目录中的application.yaml及application-{profile}.yaml，同时支持yml及json扩展名
+//! 3. 以KNIFE_开头的环境变量，层级间以双下划线分隔，如KNIFE_SERVER__PORT对应server.port
+//! 4. 命令行参数
+//!
+//! 加载后可通过ContextTrait中的方法以server.port或/server/port的方式读取配置，
+//! 并可通过Config::source查询每个配置项的来源
+mod loader;
+mod model;
+mod tests;
+
+pub use loader::ConfigLoader;
+pub use model::{Config, ConfigSource};
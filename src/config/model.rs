@@ -0,0 +1,94 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    bean::{key_to_pointer, FromValueTrait, PointerMutTrait, PointerTrait},
+    context::ContextTrait,
+    Result, Value, OK,
+};
+
+/// 配置项的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// 程序中设置的默认配置
+    Default,
+    /// 配置文件，记录文件路径
+    File(String),
+    /// 环境变量，记录环境变量名称
+    Env(String),
+    /// 命令行参数，记录参数名称
+    CommandLine(String),
+    /// 加载完成后在程序中写入的配置
+    Program,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File(v) => write!(f, "file:{}", v),
+            ConfigSource::Env(v) => write!(f, "env:{}", v),
+            ConfigSource::CommandLine(v) => write!(f, "arg:{}", v),
+            ConfigSource::Program => write!(f, "program"),
+        }
+    }
+}
+
+/// 合并后的配置
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub(super) value: Value,
+    pub(super) sources: BTreeMap<String, ConfigSource>,
+}
+
+impl Config {
+    /// 获取合并后的完整配置
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// 查询配置项的来源，配置项为数组元素等未单独记录来源的节点时返回其上层节点的来源
+    pub fn source(&self, key: &str) -> Option<&ConfigSource> {
+        let mut pointer = key_to_pointer(key);
+        loop {
+            if let Some(v) = self.sources.get(&pointer) {
+                return Some(v);
+            }
+            match pointer.rfind('/') {
+                Some(i) => pointer.truncate(i),
+                None => return None,
+            }
+        }
+    }
+
+    /// 读取配置项并转换为指定类型，失败时异常中会记录配置项的路径及来源
+    pub fn get<T: FromValueTrait>(&self, key: &str) -> Result<T> {
+        let pointer = key_to_pointer(key);
+        let value = self.value.p(pointer.as_str()).unwrap_or(&Value::Null);
+        T::from_value(value).map_err(|e| {
+            let e = e.context_value("pointer".to_string(), Value::String(pointer.clone()));
+            match self.source(key) {
+                Some(source) => {
+                    e.context_value("source".to_string(), Value::String(source.to_string()))
+                }
+                None => e,
+            }
+        })
+    }
+}
+
+impl ContextTrait for Config {
+    type Context = Value;
+
+    fn get_value(&self, key: &str) -> Result<Option<&Value>> {
+        OK(self.value.p(key_to_pointer(key).as_str()))
+    }
+
+    fn insert_value(&mut self, key: &str, value: Value) -> Result<()> {
+        let pointer = key_to_pointer(key);
+        self.value.set_by_pointer(pointer.as_str(), value)?;
+        let prefix = format!("{}/", pointer);
+        self.sources.retain(|k, _| !k.starts_with(prefix.as_str()));
+        self.sources.insert(pointer, ConfigSource::Program);
+        OK(())
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use clap::{Arg, Command};
+    use serde_json::json;
+
+    use crate::{
+        bean::AsValueTrait,
+        config::{ConfigLoader, ConfigSource},
+        context::ContextTrait,
+        error::AppError,
+    };
+
+    fn config_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("knife_util_config_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_config_loader() {
+        let dir = config_dir("loader");
+        fs::write(
+            dir.join("application.yaml"),
+            "server:\n  host: 0.0.0.0\n  port: 8080\ntags: [a, b]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("application-dev.yaml"),
+            "server:\n  port: 9090\ntags: [c]\n",
+        )
+        .unwrap();
+        let matches = Command::new("app")
+            .arg(Arg::new("name").long("name").takes_value(true))
+            .arg(
+                Arg::new("level")
+                    .long("level")
+                    .takes_value(true)
+                    .default_value("info"),
+            )
+            .get_matches_from(vec!["app", "--name", "demo"]);
+
+        let config = ConfigLoader::new()
+            .defaults(
+                json!({"app": {"name": "default", "level": "warn"}, "debug": false})
+                    .as_value()
+                    .unwrap(),
+            )
+            .config_dir(&dir)
+            .envs(vec![
+                ("KNIFE_PROFILE".to_string(), "dev".to_string()),
+                ("KNIFE_DEBUG".to_string(), "true".to_string()),
+                ("KNIFE_SERVER__MAX_CONN".to_string(), "100".to_string()),
+                ("KNIFE_APP__ZIP".to_string(), "0123".to_string()),
+                ("KNIFE_APP__VERSION".to_string(), "1.10".to_string()),
+                ("KNIFE_APP__RATIO".to_string(), "0.5".to_string()),
+                ("OTHER".to_string(), "1".to_string()),
+            ])
+            .args(&matches, &[("name", "app.name"), ("level", "app.level")])
+            .unwrap()
+            .load()
+            .unwrap();
+
+        assert_eq!(config.get_string("server.host").unwrap(), "0.0.0.0");
+        assert_eq!(config.get_i64("/server/port").unwrap(), 9090);
+        assert_eq!(config.get_i64("server.max_conn").unwrap(), 100);
+        assert!(config.get_bool("debug").unwrap());
+        assert_eq!(config.get_string("app.zip").unwrap(), "0123");
+        assert_eq!(config.get_string("app.version").unwrap(), "1.10");
+        assert_eq!(config.get::<f64>("app.ratio").unwrap(), 0.5);
+        assert_eq!(config.get_string("app.name").unwrap(), "demo");
+        assert_eq!(config.get_string("app.level").unwrap(), "warn");
+        assert_eq!(config.get::<Vec<String>>("tags").unwrap(), vec!["c"]);
+        assert!(config.get_opt_string("other").unwrap().is_none());
+
+        let dev = dir
+            .join("application-dev.yaml")
+            .to_string_lossy()
+            .to_string();
+        let base = dir.join("application.yaml").to_string_lossy().to_string();
+        assert_eq!(
+            config.source("server.port"),
+            Some(&ConfigSource::File(dev.clone()))
+        );
+        assert_eq!(
+            config.source("server.host"),
+            Some(&ConfigSource::File(base))
+        );
+        assert_eq!(config.source("tags.0"), Some(&ConfigSource::File(dev)));
+        assert_eq!(
+            config.source("server.max_conn"),
+            Some(&ConfigSource::Env("KNIFE_SERVER__MAX_CONN".to_string()))
+        );
+        assert_eq!(
+            config.source("app.name"),
+            Some(&ConfigSource::CommandLine("name".to_string()))
+        );
+        assert_eq!(config.source("app.level"), Some(&ConfigSource::Default));
+
+        let err = config.get::<i64>("server.host").unwrap_err();
+        let context = err.context_map_ref().unwrap();
+        assert_eq!(
+            context.get("pointer").unwrap().as_str().unwrap(),
+            "/server/host"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_error() {
+        let dir = config_dir("error");
+        fs::write(dir.join("application.yaml"), "server: [1\n").unwrap();
+        let err = ConfigLoader::new().config_dir(&dir).load().unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_PARSE");
+        let file = dir.join("application.yaml").to_string_lossy().to_string();
+        assert_eq!(
+            err.context_map_ref()
+                .unwrap()
+                .get("file")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            file
+        );
+
+        fs::write(dir.join("application.yaml"), "server:\n  port: 80\n").unwrap();
+        let err = ConfigLoader::new()
+            .config_dir(&dir)
+            .envs(vec![("KNIFE_SERVER".to_string(), "1".to_string())])
+            .load()
+            .unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_ENV_VAR");
+        let cause = err.cause_ref().unwrap().downcast_ref::<AppError>().unwrap();
+        assert_eq!(cause.name_ref(), "ERR_MERGE");
+        let context = err.context_map_ref().unwrap();
+        assert_eq!(context.get("pointer").unwrap().as_str().unwrap(), "/server");
+        assert_eq!(
+            context.get("source").unwrap().as_str().unwrap(),
+            "env:KNIFE_SERVER"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+use rust_decimal::Decimal;
+
+use crate::{date::YearMonth, error::ERR_DATA, CoercionPolicy, Result, Value, OK};
+
+use super::ContextTrait;
+
+/// 按宽松转换策略读取数据的上下文视图，通过ContextTrait::coerced创建
+///
+/// 值为Null的键按不存在处理，视图仅用于读取，写入时返回ERR_DATA异常
+pub struct CoercedContext<'a, C: ContextTrait> {
+    context: &'a C,
+    policy: CoercionPolicy,
+}
+
+impl<'a, C: ContextTrait> CoercedContext<'a, C> {
+    pub fn new(context: &'a C, policy: CoercionPolicy) -> Self {
+        CoercedContext { context, policy }
+    }
+
+    /// 获取当前使用的转换策略
+    pub fn policy(&self) -> &CoercionPolicy {
+        &self.policy
+    }
+
+    fn get<T>(&self, key: &str, f: impl Fn(&Value) -> Result<T>) -> Result<T> {
+        match self.context.get_value(key)? {
+            Some(v) => {
+                f(v).map_err(|e| e.context_value("key".to_string(), Value::String(key.to_string())))
+            }
+            None => Err(ERR_DATA.msg_detail(format!("{}不能为空", key).as_str())),
+        }
+    }
+
+    fn get_opt<T>(&self, key: &str, f: impl Fn(&Value) -> Result<T>) -> Result<Option<T>> {
+        match self.context.get_value(key)? {
+            Some(Value::Null) | None => OK(None),
+            Some(_) => self.get(key, f).map(Some),
+        }
+    }
+}
+
+impl<'a, C: ContextTrait> ContextTrait for CoercedContext<'a, C> {
+    type Context = C::Context;
+
+    fn get_value(&self, key: &str) -> Result<Option<&Value>> {
+        self.context.get_value(key)
+    }
+
+    fn insert_value(&mut self, key: &str, _value: Value) -> Result<()> {
+        Err(ERR_DATA.msg_detail(format!("宽松转换的上下文视图为只读，不能写入{}", key).as_str()))
+    }
+
+    fn get_bool(&self, key: &str) -> Result<bool> {
+        self.get(key, |v| v.coerce_bool(&self.policy))
+    }
+
+    fn get_bool_or(&self, key: &str, default: bool) -> Result<bool> {
+        self.get_opt(key, |v| v.coerce_bool(&self.policy))
+            .map(|x| x.unwrap_or(default))
+    }
+
+    fn get_i64(&self, key: &str) -> Result<i64> {
+        self.get(key, |v| v.coerce_i64(&self.policy))
+    }
+
+    fn get_opt_i64(&self, key: &str) -> Result<Option<i64>> {
+        self.get_opt(key, |v| v.coerce_i64(&self.policy))
+    }
+
+    fn get_u64(&self, key: &str) -> Result<u64> {
+        self.get(key, |v| v.coerce_u64(&self.policy))
+    }
+
+    fn get_opt_u64(&self, key: &str) -> Result<Option<u64>> {
+        self.get_opt(key, |v| v.coerce_u64(&self.policy))
+    }
+
+    fn get_f64(&self, key: &str) -> Result<f64> {
+        self.get(key, |v| v.coerce_f64(&self.policy))
+    }
+
+    fn get_opt_f64(&self, key: &str) -> Result<Option<f64>> {
+        self.get_opt(key, |v| v.coerce_f64(&self.policy))
+    }
+
+    fn get_decimal(&self, key: &str) -> Result<Decimal> {
+        self.get(key, |v| v.coerce_decimal(&self.policy))
+    }
+
+    fn get_opt_decimal(&self, key: &str) -> Result<Option<Decimal>> {
+        self.get_opt(key, |v| v.coerce_decimal(&self.policy))
+    }
+
+    fn get_yearmonth(&self, key: &str) -> Result<YearMonth> {
+        self.get(key, |v| v.coerce_year_month(&self.policy))
+    }
+
+    fn get_opt_yearmonth(&self, key: &str) -> Result<Option<YearMonth>> {
+        self.get_opt(key, |v| v.coerce_year_month(&self.policy))
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{bean::AsValueTrait, date::YearMonth, error::ERR_DATA, Result, Value, OK};
+use rust_decimal::Decimal;
+
+use crate::{
+    bean::AsValueTrait, date::YearMonth, error::ERR_DATA, CoercionPolicy, Result, Value, OK,
+};
+
+use super::CoercedContext;
 
 /// 键为字符类型的上下文工具类
 pub trait ContextTrait {
@@ -165,6 +171,39 @@ pub trait ContextTrait {
         }
     }
 
+    /// 集合类中取出Decimal类型
+    fn get_decimal(&self, key: &str) -> Result<Decimal> {
+        match self.get_value(key) {
+            Ok(v) => match v {
+                Some(v2) => v2.as_decimal(),
+                None => Err(ERR_DATA.msg_detail(format!("{}不能为空", key).as_str())),
+            },
+            Err(e) => Err(e),
+        }
+    }
+    /// 集合中插入Decimal类型
+    fn insert_decimal(&mut self, key: &str, value: Decimal) -> Result<()> {
+        self.insert_value(key, Value::Decimal(value))
+    }
+    /// 集合类中取出Decimal类型
+    fn get_opt_decimal(&self, key: &str) -> Result<Option<Decimal>> {
+        match self.get_value(key) {
+            Ok(v) => match v {
+                Some(v2) => v2.as_decimal().map(Some),
+                None => OK(None),
+            },
+            Err(e) => Err(e),
+        }
+    }
+    /// 集合中插入Decimal类型
+    fn insert_opt_decimal(&mut self, key: &str, value: Option<Decimal>) -> Result<()> {
+        if let Some(v) = value {
+            self.insert_value(key, Value::Decimal(v))
+        } else {
+            OK(())
+        }
+    }
+
     /// 集合类中取出年月类型
     fn get_yearmonth(&self, key: &str) -> Result<YearMonth> {
         match self.get_value(key) {
@@ -198,6 +237,14 @@ pub trait ContextTrait {
         }
     }
 
+    /// 获取按宽松转换策略读取数据的只读上下文视图，如将字符串"42"读取为i64类型
+    fn coerced(&self, policy: CoercionPolicy) -> CoercedContext<'_, Self>
+    where
+        Self: Sized,
+    {
+        CoercedContext::new(self, policy)
+    }
+
     /// 集合中插入JSON类型
     fn insert_json(&mut self, key: &str, value: &serde_json::Value) -> Result<()> {
         self.insert_value(key, value.as_value()?)
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 
-use crate::{any::AnyValue, Result, Value, OK};
+use crate::{any::AnyValue, value_type::lookup_flat, Result, SharedValue, Value, OK};
 
 use super::{AnyContextTrait, ContextTrait};
 
@@ -15,6 +15,17 @@ impl ContextTrait for HashMap<String, Value> {
     }
 }
 
+impl ContextTrait for HashMap<String, SharedValue> {
+    type Context = SharedValue;
+    fn get_value(&self, key: &str) -> Result<Option<&Value>> {
+        OK(self.get(key).map(|x| x.as_value_ref()))
+    }
+    fn insert_value(&mut self, key: &str, value: Value) -> Result<()> {
+        self.insert(key.to_string(), SharedValue::from(value));
+        OK(())
+    }
+}
+
 impl ContextTrait for HashMap<String, AnyValue> {
     type Context = AnyValue;
     fn get_value(&self, key: &str) -> Result<Option<&Value>> {
@@ -35,10 +46,12 @@ impl AnyContextTrait for HashMap<String, AnyValue> {
     }
 }
 
+/// 键不存在时可使用a.b[0].c格式的路径在已有的键对应的数据中查找，
+/// 因此Value::flatten生成的单层键值对及多层级的数据均可按相同的路径读取
 impl ContextTrait for BTreeMap<String, Value> {
     type Context = Value;
     fn get_value(&self, key: &str) -> Result<Option<&Value>> {
-        OK(self.get(key))
+        OK(lookup_flat(self, key))
     }
     fn insert_value(&mut self, key: &str, value: Value) -> Result<()> {
         self.insert(key.to_string(), value);
@@ -1,6 +1,7 @@
 use crate::{
-    bean::{AsValueTrait, MergeValueTrait},
+    bean::{AsValueTrait, FromValueTrait, MergeValueTrait},
     error::AppError,
+    value_type::{serialize_tagged, DATE_TOKEN},
     Result, Value, OK,
 };
 
@@ -22,6 +23,12 @@ impl AsValueTrait for Date {
     }
 }
 
+impl FromValueTrait for Date {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_date()
+    }
+}
+
 impl MergeValueTrait for Date {
     fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
         if let Some(v) = target {
@@ -36,7 +43,11 @@ impl serde::ser::Serialize for Date {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(self.date.format("%Y-%m-%d").to_string().as_str())
+        serialize_tagged(
+            serializer,
+            DATE_TOKEN,
+            self.date.format("%Y-%m-%d").to_string().as_str(),
+        )
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::{
-    bean::{AsValueTrait, MergeValueTrait},
+    bean::{AsValueTrait, FromValueTrait, MergeValueTrait},
     error::AppError,
+    value_type::{serialize_tagged, DATETIME_TOKEN},
     Result, Value, OK,
 };
 
@@ -22,6 +23,12 @@ impl AsValueTrait for DateTime {
     }
 }
 
+impl FromValueTrait for DateTime {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_datetime()
+    }
+}
+
 impl MergeValueTrait for DateTime {
     fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
         if let Some(v) = target {
@@ -36,7 +43,9 @@ impl serde::ser::Serialize for DateTime {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(
+        serialize_tagged(
+            serializer,
+            DATETIME_TOKEN,
             self.datetime
                 .format("%Y-%m-%d %H:%M:%S")
                 .to_string()
@@ -89,4 +98,8 @@ impl DateTime {
     pub fn to_chrono_date(&self) -> chrono::NaiveDate {
         self.datetime.date()
     }
+
+    pub fn to_chrono_datetime(&self) -> chrono::NaiveDateTime {
+        self.datetime
+    }
 }
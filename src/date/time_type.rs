@@ -1,6 +1,7 @@
 use crate::{
-    bean::{AsValueTrait, MergeValueTrait},
+    bean::{AsValueTrait, FromValueTrait, MergeValueTrait},
     error::AppError,
+    value_type::{serialize_tagged, TIME_TOKEN},
     Result, Value, OK,
 };
 
@@ -22,6 +23,12 @@ impl AsValueTrait for Time {
     }
 }
 
+impl FromValueTrait for Time {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_time()
+    }
+}
+
 impl MergeValueTrait for Time {
     fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
         if let Some(v) = target {
@@ -36,7 +43,11 @@ impl serde::ser::Serialize for Time {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(self.time.format("%H:%M:%S").to_string().as_str())
+        serialize_tagged(
+            serializer,
+            TIME_TOKEN,
+            self.time.format("%H:%M:%S").to_string().as_str(),
+        )
     }
 }
 
@@ -80,4 +91,8 @@ impl Time {
             })
             .map(|x| Time { time: x })
     }
+
+    pub fn to_chrono_time(&self) -> chrono::NaiveTime {
+        self.time
+    }
 }
@@ -1,8 +1,9 @@
 use chrono::Datelike;
 
 use crate::{
-    bean::{AsValueTrait, MergeValueTrait},
+    bean::{AsValueTrait, FromValueTrait, MergeValueTrait},
     error::AppError,
+    value_type::{serialize_tagged, YEARMONTH_TOKEN},
     Result, Value, OK,
 };
 
@@ -29,6 +30,12 @@ impl AsValueTrait for YearMonth {
     }
 }
 
+impl FromValueTrait for YearMonth {
+    fn from_value(value: &Value) -> Result<Self> {
+        value.as_year_month()
+    }
+}
+
 impl MergeValueTrait for YearMonth {
     fn merge_value(&mut self, target: Option<&Value>) -> Result<Self> {
         if let Some(v) = target {
@@ -43,7 +50,11 @@ impl serde::ser::Serialize for YearMonth {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(self.date.format("%Y-%m").to_string().as_str())
+        serialize_tagged(
+            serializer,
+            YEARMONTH_TOKEN,
+            self.date.format("%Y-%m").to_string().as_str(),
+        )
     }
 }
 
@@ -91,6 +102,11 @@ impl YearMonth {
             .map(|x| YearMonth { date: x })
     }
 
+    /// 获取当月第一天对应的日期
+    pub fn to_chrono_date(&self) -> chrono::NaiveDate {
+        self.date
+    }
+
     pub fn is_leap_year(&self) -> bool {
         is_leap_year(self.date.year())
     }
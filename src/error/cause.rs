@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use super::AppError;
+
+/// AppError中保存的错误原因
+///
+/// AppError类型的错误原因保留完整数据，可通过downcast_ref还原；其余类型的错误原因保存为快照，
+/// 仅保留其Display及Debug格式的信息及来源链，从而不要求错误类型实现Send及Sync
+#[derive(Clone)]
+pub(super) enum ErrorCause {
+    App(AppError),
+    Snapshot(CauseSnapshot),
+}
+
+#[derive(Clone)]
+pub(super) struct CauseSnapshot {
+    display: String,
+    debug: String,
+    source: Option<Box<ErrorCause>>,
+}
+
+impl ErrorCause {
+    pub(super) fn capture(error: &(dyn std::error::Error + 'static)) -> Self {
+        if let Some(e) = error.downcast_ref::<AppError>() {
+            return ErrorCause::App(e.clone());
+        }
+        ErrorCause::Snapshot(CauseSnapshot {
+            display: error.to_string(),
+            debug: format!("{:?}", error),
+            source: error.source().map(|x| Box::new(ErrorCause::capture(x))),
+        })
+    }
+
+    pub(super) fn as_error(&self) -> &(dyn std::error::Error + 'static) {
+        match self {
+            ErrorCause::App(e) => e,
+            ErrorCause::Snapshot(e) => e,
+        }
+    }
+}
+
+impl std::fmt::Debug for CauseSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.debug.as_str())
+    }
+}
+
+impl Display for CauseSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.display.as_str())
+    }
+}
+
+impl std::error::Error for CauseSnapshot {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(ErrorCause::as_error)
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use hyper::{
+    header::{HeaderValue, CONTENT_TYPE},
+    Body, Response, StatusCode,
+};
+use lazy_static::lazy_static;
+
+use crate::{bean::AsValueTrait, Result, Value, OK};
+
+use super::{
+    error_registry, main::json_string, wire::RemoteCause, AppError, ERR_ARGUMENT, ERR_PARSE,
+    ERR_VALIDATION, ERR_WEB,
+};
+
+/// 输出错误响应时记录链路追踪标识的响应头
+///
+/// nginx等代理默认会丢弃名称中包含下划线的响应头，因此使用中划线分隔
+pub const TRACE_ID_HEADER: &str = "x-trace-id";
+
+lazy_static! {
+    /// 内置错误的默认HTTP状态码，其余错误均为500
+    static ref DEFAULT_STATUS: HashMap<String, StatusCode> = HashMap::from([
+        (ERR_ARGUMENT.code_ref().to_string(), StatusCode::BAD_REQUEST),
+        (ERR_VALIDATION.code_ref().to_string(), StatusCode::UNPROCESSABLE_ENTITY),
+        (ERR_WEB.code_ref().to_string(), StatusCode::BAD_GATEWAY),
+    ]);
+}
+
+/// 获取错误码对应的默认HTTP状态码
+pub(super) fn default_status(code: &str) -> StatusCode {
+    DEFAULT_STATUS
+        .get(code)
+        .copied()
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// 转换为HTTP响应的选项
+#[derive(Debug, Clone)]
+pub struct ResponseOptions {
+    debug: bool,
+    trace_id: Option<String>,
+    locale: Option<String>,
+}
+
+impl Default for ResponseOptions {
+    fn default() -> Self {
+        ResponseOptions {
+            debug: cfg!(debug_assertions),
+            trace_id: None,
+            locale: None,
+        }
+    }
+}
+
+impl ResponseOptions {
+    /// 默认在debug编译模式下输出错误原因及堆栈信息
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置是否输出错误原因及堆栈信息
+    pub fn debug(mut self, value: bool) -> Self {
+        self.debug = value;
+        self
+    }
+
+    /// 设置链路追踪标识，输出到响应头中
+    pub fn trace_id(mut self, value: &str) -> Self {
+        self.trace_id = Some(value.to_string());
+        self
+    }
+
+    /// 设置输出错误信息的语言，按全局错误信息目录进行转换
+    pub fn locale(mut self, value: &str) -> Self {
+        self.locale = Some(value.to_string());
+        self
+    }
+}
+
+impl AppError {
+    /// 获取转换为HTTP响应时使用的状态码
+    ///
+    /// 优先使用全局注册表中为该错误指定的状态码，其余按内置错误的默认状态码处理
+    pub fn status_code(&self) -> StatusCode {
+        error_registry()
+            .get_by_code(self.code_ref())
+            .filter(|x| x.name == self.name_ref())
+            .and_then(|x| StatusCode::from_u16(x.status).ok())
+            .unwrap_or_else(|| default_status(self.code_ref()))
+    }
+
+    /// 转换为HTTP响应，响应体为Json格式，仅在debug编译模式下输出错误原因及堆栈信息
+    pub fn into_hyper_response(self) -> Response<Body> {
+        self.into_hyper_response_with(&ResponseOptions::default())
+    }
+
+    /// 按指定选项转换为HTTP响应，指定语言时输出本地化的错误信息，不合法的链路追踪标识不会输出到响应头中
+    pub fn into_hyper_response_with(self, options: &ResponseOptions) -> Response<Body> {
+        let error = match &options.locale {
+            Some(locale) => self.localized(locale),
+            None => self,
+        };
+        let body = json_string(&error.to_body_value(options.debug, options.debug));
+        let mut response = Response::new(Body::from(body));
+        *response.status_mut() = error.status_code();
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+        if let Some(trace_id) = options
+            .trace_id
+            .as_ref()
+            .and_then(|x| HeaderValue::from_str(x).ok())
+        {
+            response.headers_mut().insert(TRACE_ID_HEADER, trace_id);
+        }
+        response
+    }
+
+    /// 解析上游服务返回的错误信息
+    ///
+    /// 需包含字符串类型的name及code，cause转换为错误原因，backtrace被忽略，其余字段均作为上下文变量
+    pub fn from_json_str(body: &str) -> Result<AppError> {
+        let parse_err = |detail: &str| {
+            ERR_PARSE
+                .msg_detail(detail)
+                .context_value("body".to_string(), Value::String(body.to_string()))
+        };
+        let value = serde_json::from_str::<serde_json::Value>(body)
+            .map_err(|e| parse_err("上游服务返回的错误信息不是合法的Json格式").cause(e))?
+            .as_value()?;
+        let mut map = match value {
+            Value::Object(map) => map,
+            _ => return Err(parse_err("上游服务返回的错误信息不是Json对象")),
+        };
+        let mut take_str = |key: &str| match map.remove(key) {
+            Some(Value::String(v)) => Some(v),
+            _ => None,
+        };
+        let (name, code) = match (take_str("name"), take_str("code")) {
+            (Some(name), Some(code)) => (name, code),
+            _ => return Err(parse_err("上游服务返回的错误信息缺少name或code字段")),
+        };
+        let mut err = AppError::new(name, code, take_str("msg").unwrap_or_default());
+        if let Some(detail) = take_str("msg_detail") {
+            err = err.msg_detail(detail.as_str());
+        }
+        if let Some(cause) = take_str("cause") {
+            err = err.cause(RemoteCause::new(cause));
+        }
+        map.remove("backtrace");
+        for (k, v) in map {
+            err = err.context_value(k, v);
+        }
+        OK(err)
+    }
+
+    /// 读取上游服务的HTTP响应并转换为错误信息
+    ///
+    /// 响应体无法解析时返回ERR_WEB异常，并在上下文变量中记录状态码及响应体
+    pub async fn from_hyper_response(response: Response<Body>) -> AppError {
+        let status = response.status();
+        let body = match hyper::body::to_bytes(response.into_body()).await {
+            Ok(v) => String::from_utf8_lossy(&v).to_string(),
+            Err(e) => return AppError::from(e),
+        };
+        match AppError::from_json_str(body.as_str()) {
+            Ok(err) => err,
+            Err(_) => ERR_WEB
+                .msg_detail(
+                    format!(
+                        "上游服务返回了无法解析的错误信息，状态码为[{}]",
+                        status.as_u16()
+                    )
+                    .as_str(),
+                )
+                .context_value("status".to_string(), Value::U32(status.as_u16().into()))
+                .context_value("body".to_string(), Value::String(body)),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{RwLock, RwLockReadGuard},
+};
+
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use crate::{template::render_text_template, Result, Value, OK};
+
+use super::{AppError, ERR_ARGUMENT, ERR_PARSE};
+
+lazy_static! {
+    static ref GLOBAL_CATALOG: RwLock<MessageCatalog> = RwLock::new(MessageCatalog::new());
+}
+
+/// 本地化的错误信息，可使用`{{key}}`引用错误中的上下文变量
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum LocalizedMessage {
+    /// 仅替换错误信息
+    Text(String),
+
+    /// 替换错误信息及错误详情
+    Full {
+        msg: String,
+        #[serde(default)]
+        msg_detail: Option<String>,
+    },
+}
+
+impl LocalizedMessage {
+    pub fn msg(&self) -> &str {
+        match self {
+            LocalizedMessage::Text(msg) => msg,
+            LocalizedMessage::Full { msg, .. } => msg,
+        }
+    }
+
+    pub fn msg_detail(&self) -> Option<&str> {
+        match self {
+            LocalizedMessage::Text(_) => None,
+            LocalizedMessage::Full { msg_detail, .. } => msg_detail.as_deref(),
+        }
+    }
+}
+
+/// 错误信息目录，按语言及错误名称存储本地化的错误信息
+///
+/// 每个语言对应一个以错误名称为键的Yaml或Json文件，值为错误信息，或包含msg及msg_detail的对象：
+///
+/// ```yaml
+/// ERR_IO: IO operation failed
+/// ERR_VALIDATION:
+///   msg: Validation failed
+///   msg_detail: "Field {{field}} is invalid"
+/// ```
+///
+/// 语言标识不区分大小写，`zh_CN`与`zh-CN`视为同一语言
+#[derive(Debug, Default)]
+pub struct MessageCatalog {
+    locales: HashMap<String, HashMap<String, LocalizedMessage>>,
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加指定语言的错误信息，已存在的错误名称会被覆盖
+    pub fn insert(&mut self, locale: &str, name: &str, message: LocalizedMessage) {
+        self.locales
+            .entry(normalize_locale(locale))
+            .or_default()
+            .insert(name.to_string(), message);
+    }
+
+    /// 加载Yaml格式的错误信息
+    pub fn add_yaml(&mut self, locale: &str, content: &str) -> Result<()> {
+        let messages = serde_yaml::from_str::<HashMap<String, LocalizedMessage>>(content)
+            .map_err(|e| invalid_catalog(locale).cause(e))?;
+        self.extend(locale, messages);
+        OK(())
+    }
+
+    /// 加载Json格式的错误信息
+    pub fn add_json(&mut self, locale: &str, content: &str) -> Result<()> {
+        let messages = serde_json::from_str::<HashMap<String, LocalizedMessage>>(content)
+            .map_err(|e| invalid_catalog(locale).cause(e))?;
+        self.extend(locale, messages);
+        OK(())
+    }
+
+    /// 按文件扩展名加载yaml、yml或json格式的错误信息文件
+    pub fn add_file<P: AsRef<Path>>(&mut self, locale: &str, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|x| x.to_str()) {
+            Some("yaml") | Some("yml") => self.add_yaml(locale, content.as_str()),
+            Some("json") => self.add_json(locale, content.as_str()),
+            _ => Err(ERR_ARGUMENT
+                .msg_detail(format!("不支持的错误信息文件格式[{}]", path.display()).as_str())),
+        }
+    }
+
+    /// 加载目录下所有yaml、yml及json格式的错误信息文件，文件名即为语言标识，如`en.yaml`、`zh-CN.json`
+    pub fn add_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let supported = matches!(
+                path.extension().and_then(|x| x.to_str()),
+                Some("yaml") | Some("yml") | Some("json")
+            );
+            if let Some(locale) = path.file_stem().and_then(|x| x.to_str()) {
+                if supported && path.is_file() {
+                    self.add_file(locale, &path)?;
+                }
+            }
+        }
+        OK(())
+    }
+
+    fn extend(&mut self, locale: &str, messages: HashMap<String, LocalizedMessage>) {
+        self.locales
+            .entry(normalize_locale(locale))
+            .or_default()
+            .extend(messages);
+    }
+
+    /// 已加载的语言标识，均已转换为小写并以`-`分隔
+    pub fn locales(&self) -> Vec<&str> {
+        let mut res: Vec<&str> = self.locales.keys().map(|x| x.as_str()).collect();
+        res.sort();
+        res
+    }
+
+    /// 获取指定语言的错误信息，不存在时使用同一主语言的错误信息，如`en-US`可使用`en`中的错误信息
+    pub fn get(&self, locale: &str, name: &str) -> Option<&LocalizedMessage> {
+        let locale = normalize_locale(locale);
+        let found = self.locales.get(&locale).and_then(|x| x.get(name));
+        if found.is_some() {
+            return found;
+        }
+        let primary = primary_language(&locale);
+        if let Some(v) = self.locales.get(primary).and_then(|x| x.get(name)) {
+            return Some(v);
+        }
+        self.locales
+            .iter()
+            .filter(|(k, _)| primary_language(k) == primary)
+            .min_by_key(|(k, _)| k.as_str())
+            .and_then(|(_, v)| v.get(name))
+    }
+
+    /// 根据Accept-Language请求头选择已加载的语言
+    pub fn negotiate(&self, accept_language: &str) -> Option<String> {
+        negotiate_locale(accept_language, &self.locales())
+    }
+}
+
+fn invalid_catalog(locale: &str) -> AppError {
+    ERR_PARSE.msg_detail(format!("语言[{}]的错误信息目录格式不正确", locale).as_str())
+}
+
+/// 转换为小写并以`-`分隔的语言标识
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_lowercase()
+}
+
+fn primary_language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+/// 根据Accept-Language请求头从支持的语言中选择最合适的语言
+///
+/// 按q值从高到低依次匹配，语言标识完全一致时优先，其次匹配主语言相同的语言，
+/// `*`匹配第一个支持的语言，q值为0或不合法的语言不会被选择，均无法匹配时返回None
+///
+/// ```
+/// use knife_util::error::negotiate_locale;
+///
+/// let locale = negotiate_locale("fr;q=0.9, en-GB;q=0.8, zh;q=0.5", &["zh-CN", "en"]);
+/// assert_eq!(locale.as_deref(), Some("en"));
+/// ```
+pub fn negotiate_locale(accept_language: &str, supported: &[&str]) -> Option<String> {
+    let mut ranges: Vec<(String, f32)> = accept_language
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = normalize_locale(parts.next()?);
+            let q = match parts.find_map(|x| x.trim().strip_prefix("q=")) {
+                Some(v) => v.trim().parse::<f32>().ok().filter(|x| x.is_finite())?,
+                None => 1.0,
+            };
+            (!tag.is_empty() && q > 0.0).then_some((tag, q))
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (tag, _) in ranges {
+        if tag == "*" {
+            return supported.first().map(|x| x.to_string());
+        }
+        let exact = supported.iter().find(|x| normalize_locale(x) == tag);
+        let similar = || {
+            supported
+                .iter()
+                .find(|x| primary_language(&normalize_locale(x)) == primary_language(&tag))
+        };
+        if let Some(v) = exact.or_else(similar) {
+            return Some(v.to_string());
+        }
+    }
+    None
+}
+
+/// 替换全局错误信息目录
+pub fn set_message_catalog(catalog: MessageCatalog) {
+    *GLOBAL_CATALOG.write().unwrap() = catalog;
+}
+
+/// 获取全局错误信息目录
+pub fn message_catalog() -> RwLockReadGuard<'static, MessageCatalog> {
+    GLOBAL_CATALOG.read().unwrap()
+}
+
+impl AppError {
+    /// 按全局错误信息目录转换为指定语言的错误，并复制一个全新的错误对象
+    pub fn localized(&self, locale: &str) -> Self {
+        self.localized_with(&message_catalog(), locale)
+    }
+
+    /// 按指定错误信息目录转换为指定语言的错误
+    ///
+    /// 错误信息及错误详情中的变量使用上下文变量进行渲染，未配置该语言、错误详情或渲染失败时保留原有内容
+    pub fn localized_with(&self, catalog: &MessageCatalog, locale: &str) -> Self {
+        let message = match catalog.get(locale, self.name_ref()) {
+            Some(v) => v,
+            None => return self.clone(),
+        };
+        let ctx = Value::Object(
+            self.context_map_ref()
+                .map(|x| x.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+        );
+        let msg = render_text_template(message.msg(), &ctx)
+            .unwrap_or_else(|_| self.msg_ref().to_string());
+        let msg_detail = message
+            .msg_detail()
+            .and_then(|x| render_text_template(x, &ctx).ok());
+        self.with_messages(msg, msg_detail)
+    }
+}
@@ -16,6 +16,15 @@ impl std::error::Error for AppError {
     }
 }
 
+/// 错误码及错误名称均相同时视为同一错误，跨服务接收的错误可直接与错误常量进行比较
+impl PartialEq for AppError {
+    fn eq(&self, other: &Self) -> bool {
+        self.code_ref() == other.code_ref() && self.name_ref() == other.name_ref()
+    }
+}
+
+impl Eq for AppError {}
+
 impl serde::ser::Error for AppError {
     fn custom<T: Display>(msg: T) -> Self {
         ERR_SERIALIZE.msg_detail(msg.to_string().as_str())
@@ -0,0 +1,55 @@
+/// 定义错误常量，并在程序启动时注册到全局错误码注册表中
+///
+/// 首行声明错误码级别，可选Framework、Project及Application，
+/// 每个错误常量的名称即为错误名称，可在错误信息后指定转换为HTTP响应时使用的状态码，
+/// 错误名称或错误码重复、错误码位数与级别不符时不会中断启动，
+/// 可在启动后通过`error_registry().validate()`检查
+///
+/// ```
+/// use knife_util::{define_errors, error::error_registry};
+///
+/// define_errors! {
+///     Application;
+///
+///     /// 订单不存在
+///     ERR_ORDER_NOT_FOUND = ("200000001", "订单不存在", 404);
+/// }
+///
+/// let entry = error_registry().get_by_code("200000001").cloned().unwrap();
+/// assert_eq!(entry.name, "ERR_ORDER_NOT_FOUND");
+/// assert_eq!(entry.status, 404);
+/// assert!(error_registry().validate().is_ok());
+/// assert_eq!(ERR_ORDER_NOT_FOUND.msg_ref(), "订单不存在");
+/// ```
+#[macro_export]
+macro_rules! define_errors {
+    (@status) => {
+        None
+    };
+    (@status $status:expr) => {
+        Some($status)
+    };
+    ($tier:ident; $($(#[$meta:meta])* $name:ident = ($code:expr, $msg:expr $(, $status:expr)?);)*) => {
+        $crate::error::lazy_static! {
+            $(
+                $(#[$meta])*
+                pub static ref $name: $crate::error::AppError =
+                    $crate::error::AppError::new(stringify!($name), $code, $msg);
+            )*
+        }
+
+        const _: () = {
+            #[$crate::crates::ctor::ctor]
+            fn register_errors() {
+                $(
+                    $crate::error::define_error(
+                        $crate::error::ErrorTier::$tier,
+                        module_path!(),
+                        &$name,
+                        $crate::define_errors!(@status $($status)?),
+                    );
+                )*
+            }
+        };
+    };
+}
@@ -1,189 +1,199 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
 
 use backtrace::Backtrace;
-use serde_json::json;
 
-use crate::{
-    any::AnyValue,
-    bean::{AsValueTrait, FromValueTrait},
-    context::ContextTrait,
-    Value,
-};
+use crate::{bean::FromValueTrait, context::ContextTrait, Value};
 
-use super::backtrace::enable_backtrace;
+use super::{backtrace::enable_backtrace, cause::ErrorCause, wire::ErrorOrigin};
 
 /// 异常信息
 ///
 /// 统一全司错误码规范，所有错误信息均需包含此结构体中的内容
-#[derive(Clone, Copy)]
+///
+/// 所有数据均由错误对象自身持有，各构建方法均返回复制后的全新对象，不会修改原对象及共享的错误常量，
+/// 堆栈信息创建后不可修改，复制时仅增加引用计数
+#[derive(Clone)]
 pub struct AppError {
     /// 错误名称，如:ERR_INTERNAL
-    name: &'static str,
+    name: Cow<'static, str>,
 
     /// 错误码，6位及以下为该框架提供的通用错误规范，7-8位由项目方统一指定错误码，9位及以上为各应用自行设定
-    code: &'static str,
+    code: Cow<'static, str>,
 
     /// 错误信息，通用信息，仅描述错误类型
-    msg: &'static str,
+    msg: Cow<'static, str>,
 
     /// 错误信息，详情，可结合自定义参数设置输出格式
-    msg_detail: Option<&'static str>,
+    msg_detail: Option<Cow<'static, str>>,
 
     /// 错误原因，通常是被包含的内部错误
-    cause: AnyValue,
+    cause: Option<Box<ErrorCause>>,
 
     /// 异常出错堆栈信息
-    backtrace: AnyValue,
+    backtrace: Option<Arc<Backtrace>>,
 
     /// 上下文变量
-    context_map: AnyValue,
-}
+    context_map: HashMap<String, Value>,
 
-unsafe impl Sync for AppError {}
-unsafe impl Send for AppError {}
+    /// 跨服务传递时错误的来源
+    origin: Option<ErrorOrigin>,
+}
 
 impl AppError {
-    pub fn new(name: &str, code: &str, msg: &str) -> Self {
-        unsafe {
-            AppError {
-                name: &*(name as *const str),
-                code: &*(code as *const str),
-                msg: &*(msg as *const str),
-                msg_detail: None,
-                cause: AnyValue::new_zero(),
-                backtrace: AnyValue::new_zero(),
-                context_map: AnyValue::new_zero(),
-            }
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+        msg: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        AppError {
+            name: name.into(),
+            code: code.into(),
+            msg: msg.into(),
+            msg_detail: None,
+            cause: None,
+            backtrace: None,
+            context_map: HashMap::new(),
+            origin: None,
         }
     }
 
     /// 设置错误信息，并复制一个全新的错误对象
     pub fn msg_detail(&self, value: &str) -> Self {
-        unsafe {
-            let mut target = *self;
-            target.msg_detail.replace(&*(value as *const str));
-            if enable_backtrace() && target.backtrace.is_empty() {
-                target.backtrace.replace(Backtrace::new());
-            }
-            target
-        }
+        let mut target = self.clone();
+        target.msg_detail = Some(Cow::Owned(value.to_string()));
+        target.capture_backtrace()
     }
 
     /// 设置内部来源错误，并复制一个全新的包含AppError原因的错误对象
+    ///
+    /// AppError以外的错误原因仅保留其错误信息及来源链，无法通过downcast_ref还原为原始类型
     pub fn cause<E>(&self, value: E) -> Self
     where
         E: std::error::Error + 'static,
     {
-        let target = *self;
-        target.cause.replace(unsafe {
-            Box::<dyn std::error::Error + 'static>::from_raw(
-                Box::<dyn std::error::Error + 'static>::into_raw(Box::new(value)),
-            )
-        });
-        if enable_backtrace() && target.backtrace.is_empty() {
-            target.backtrace.replace(Backtrace::new());
-        }
-        target
+        let mut target = self.clone();
+        target.cause = Some(Box::new(ErrorCause::capture(&value)));
+        target.capture_backtrace()
     }
 
-    /// 在异常中存储可序列化的键值对
+    /// 在异常中存储可序列化的键值对，并复制一个全新的错误对象
     pub fn context_value(&self, key: String, value: Value) -> Self {
-        if self.context_map.is_empty() {
-            let target = *self;
-            target.context_map.replace(HashMap::from([(key, value)]));
-            if enable_backtrace() && target.backtrace.is_empty() {
-                target.backtrace.replace(Backtrace::new());
-            }
-            target
-        } else {
-            let _ = self
-                .context_map
-                .to_mut::<HashMap<String, Value>>()
-                .insert(key, value);
-            *self
+        let mut target = self.clone();
+        target.context_map.insert(key, value);
+        target.capture_backtrace()
+    }
+
+    /// 设置错误的来源服务及链路追踪标识，并复制一个全新的错误对象
+    pub fn origin(&self, origin: ErrorOrigin) -> Self {
+        let mut target = self.clone();
+        target.origin = Some(origin);
+        target
+    }
+
+    /// 替换错误信息及错误详情，并复制一个全新的错误对象
+    pub(super) fn with_messages(&self, msg: String, msg_detail: Option<String>) -> Self {
+        let mut target = self.clone();
+        target.msg = Cow::Owned(msg);
+        if msg_detail.is_some() {
+            target.msg_detail = msg_detail.map(Cow::Owned);
         }
+        target
     }
 
     /// 设置堆栈诊断信息
     pub fn backtrace(&self) -> Self {
-        let target = *self;
-        if target.backtrace.is_empty() {
-            target.backtrace.replace(Backtrace::new());
+        let mut target = self.clone();
+        if target.backtrace.is_none() {
+            target.backtrace = Some(Arc::new(Backtrace::new()));
         }
         target
     }
 
+    /// 开启堆栈输出时记录堆栈信息
+    fn capture_backtrace(self) -> Self {
+        if enable_backtrace() {
+            self.backtrace()
+        } else {
+            self
+        }
+    }
+
     /// 定义输出到前端的格式
     pub fn to_json_string(&self) -> String {
-        let mut ctx = json!( {
-            "name": self.name,
-            "code": self.code,
-            "msg": self.msg,
-            "msg_detail": self.msg_detail,
-        })
-        .as_value()
-        .unwrap();
-        if !self.cause.is_empty() {
-            let cause = self.cause.to_ref::<Box<dyn std::error::Error + 'static>>();
+        json_string(&self.to_body_value(true, false))
+    }
+
+    /// 生成输出到前端的数据，上下文变量与错误信息位于同一层级
+    ///
+    /// 与错误信息同名的上下文变量不会输出，避免覆盖错误名称、错误码等信息
+    pub(super) fn to_body_value(&self, with_cause: bool, with_backtrace: bool) -> Value {
+        let mut ctx = crate::value!({
+            "name": self.name_ref(),
+            "code": self.code_ref(),
+            "msg": self.msg_ref(),
+            "msg_detail": self.msg_detail_ref(),
+        });
+        if let Some(cause) = self.cause_ref().filter(|_| with_cause) {
             let cause_str = format!("{:?}", cause);
             ctx.insert_value("cause", Value::String(cause_str)).unwrap();
         }
-        if !self.context_map.is_empty()
-            && !self
-                .context_map
-                .to_ref::<HashMap<String, Value>>()
-                .is_empty()
-        {
-            for (k, v) in self.context_map.to_ref::<HashMap<String, Value>>() {
+        if let Some(backtrace) = self.backtrace_ref().filter(|_| with_backtrace) {
+            let backtrace_str = format!("{:?}", backtrace);
+            ctx.insert_value("backtrace", Value::String(backtrace_str))
+                .unwrap();
+        }
+        for (k, v) in &self.context_map {
+            if !BODY_KEYS.contains(&k.as_str()) {
                 ctx.insert_value(k, v.clone()).unwrap();
             }
         }
-        match serde_json::Value::from_value(&ctx) {
-            Ok(v) => serde_json::to_string(&v).unwrap_or("转换JSON格式失败".to_string()),
-            Err(e) => e.to_string(),
-        }
+        ctx
     }
 
     pub fn name_ref(&self) -> &str {
-        self.name
+        &self.name
     }
 
     pub fn code_ref(&self) -> &str {
-        self.code
+        &self.code
     }
 
     pub fn msg_ref(&self) -> &str {
-        self.msg
+        &self.msg
     }
 
     pub fn msg_detail_ref(&self) -> Option<&str> {
-        self.msg_detail
+        self.msg_detail.as_deref()
     }
 
     pub fn context_map_ref(&self) -> Option<&HashMap<String, Value>> {
         if !self.context_map.is_empty() {
-            Some(self.context_map.to_ref::<HashMap<String, Value>>())
+            Some(&self.context_map)
         } else {
             None
         }
     }
 
     pub fn cause_ref(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        if !self.cause.is_empty() {
-            Some(
-                self.cause
-                    .to_ref::<Box<dyn std::error::Error + 'static>>()
-                    .as_ref(),
-            )
-        } else {
-            None
-        }
+        self.cause.as_deref().map(ErrorCause::as_error)
     }
+
     pub fn backtrace_ref(&self) -> Option<&Backtrace> {
-        if !self.backtrace.is_empty() {
-            Some(self.backtrace.to_ref::<Backtrace>())
-        } else {
-            None
-        }
+        self.backtrace.as_deref()
+    }
+
+    pub fn origin_ref(&self) -> Option<&ErrorOrigin> {
+        self.origin.as_ref()
+    }
+}
+
+/// 输出到前端的数据中错误信息所使用的字段
+pub(super) const BODY_KEYS: [&str; 6] = ["name", "code", "msg", "msg_detail", "cause", "backtrace"];
+
+/// 转换为Json格式字符串
+pub(super) fn json_string(value: &Value) -> String {
+    match serde_json::Value::from_value(value) {
+        Ok(v) => serde_json::to_string(&v).unwrap_or("转换JSON格式失败".to_string()),
+        Err(e) => e.to_string(),
     }
 }
@@ -1,11 +1,27 @@
 //! 通用错误处理工具
 mod backtrace;
+mod cause;
 mod constant;
 mod display;
 mod from;
+mod http;
+mod i18n;
 mod implement;
+mod macros;
 mod main;
+mod registry;
 mod tests;
+mod wire;
 
 pub use constant::*;
+pub use http::{ResponseOptions, TRACE_ID_HEADER};
+pub use i18n::{
+    message_catalog, negotiate_locale, set_message_catalog, LocalizedMessage, MessageCatalog,
+};
+pub use lazy_static::lazy_static;
 pub use main::AppError;
+pub use registry::{
+    define_error, error_registry, register_error, set_error_status, ErrorEntry, ErrorRegistry,
+    ErrorTier,
+};
+pub use wire::ErrorOrigin;
@@ -0,0 +1,312 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    sync::{PoisonError, RwLock, RwLockReadGuard},
+};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::{Result, Value, OK};
+
+use super::{http::default_status, AppError, ERR_REGISTRY};
+
+lazy_static! {
+    static ref GLOBAL_REGISTRY: RwLock<ErrorRegistry> = RwLock::new(ErrorRegistry::new());
+}
+
+/// 错误码级别，按错误码的位数区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorTier {
+    /// 6位及以下，由该框架提供的通用错误
+    Framework,
+
+    /// 7-8位，由项目方统一指定的错误
+    Project,
+
+    /// 9位及以上，由各应用自行设定的错误
+    Application,
+}
+
+impl ErrorTier {
+    /// 根据错误码的位数获取对应级别
+    pub fn of(code: &str) -> ErrorTier {
+        match code.len() {
+            0..=6 => ErrorTier::Framework,
+            7..=8 => ErrorTier::Project,
+            _ => ErrorTier::Application,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorTier::Framework => "framework",
+            ErrorTier::Project => "project",
+            ErrorTier::Application => "application",
+        }
+    }
+}
+
+impl Display for ErrorTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 已注册的错误信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorEntry {
+    pub name: String,
+    pub code: String,
+    pub msg: String,
+    pub tier: ErrorTier,
+
+    /// 定义该错误的模块路径
+    pub module: String,
+
+    /// 转换为HTTP响应时使用的状态码，未指定时使用默认的状态码
+    pub status: u16,
+}
+
+/// 错误码注册表
+///
+/// 注册时校验错误名称及错误码不可重复，错误码需由数字组成且位数与声明的级别一致，
+/// 通过define_errors!定义的错误会在程序启动时自动注册到全局注册表中，
+/// 注册失败的错误不会中断启动，可通过validate检查
+#[derive(Debug, Default)]
+pub struct ErrorRegistry {
+    /// 按错误码位数及错误码排序的错误信息，即按数值大小排序
+    entries: BTreeMap<(usize, String), ErrorEntry>,
+
+    /// 错误名称与错误码的对应关系
+    names: HashMap<String, String>,
+
+    /// 通过define_errors!定义时注册失败的错误
+    conflicts: Vec<AppError>,
+}
+
+impl ErrorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册错误信息，错误名称或错误码已存在、错误码格式与级别不符时返回ERR_REGISTRY异常
+    pub fn register(&mut self, tier: ErrorTier, module: &str, error: &AppError) -> Result<()> {
+        let name = error.name_ref();
+        let code = error.code_ref();
+        let fail = |detail: String| {
+            ERR_REGISTRY
+                .msg_detail(detail.as_str())
+                .context_value("conflict_name".to_string(), Value::String(name.to_string()))
+                .context_value("conflict_code".to_string(), Value::String(code.to_string()))
+                .context_value(
+                    "conflict_module".to_string(),
+                    Value::String(module.to_string()),
+                )
+        };
+        if name.is_empty() {
+            return Err(fail(format!("错误码[{}]未指定错误名称", code)));
+        }
+        if code.is_empty() || !code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(fail(format!(
+                "错误[{}]的错误码[{}]需由数字组成",
+                name, code
+            )));
+        }
+        if ErrorTier::of(code) != tier {
+            return Err(fail(format!(
+                "错误[{}]的错误码[{}]为{}位，不符合{}级别的位数要求",
+                name,
+                code,
+                code.len(),
+                tier
+            )));
+        }
+        if let Some(exist) = self.get_by_name(name) {
+            return Err(fail(format!(
+                "错误名称[{}]已由模块[{}]注册，错误码为[{}]",
+                name, exist.module, exist.code
+            )));
+        }
+        if let Some(exist) = self.get_by_code(code) {
+            return Err(fail(format!(
+                "错误码[{}]已由模块[{}]注册，错误名称为[{}]",
+                code, exist.module, exist.name
+            )));
+        }
+        self.names.insert(name.to_string(), code.to_string());
+        self.entries.insert(
+            (code.len(), code.to_string()),
+            ErrorEntry {
+                name: name.to_string(),
+                code: code.to_string(),
+                msg: error.msg_ref().to_string(),
+                tier,
+                module: module.to_string(),
+                status: default_status(code).as_u16(),
+            },
+        );
+        OK(())
+    }
+
+    /// 指定错误转换为HTTP响应时使用的状态码，错误未注册或状态码不合法时返回ERR_REGISTRY异常
+    pub fn set_status(&mut self, error: &AppError, status: u16) -> Result<()> {
+        if hyper::StatusCode::from_u16(status).is_err() {
+            return Err(ERR_REGISTRY
+                .msg_detail(
+                    format!(
+                        "错误[{}]指定的HTTP状态码[{}]不合法",
+                        error.name_ref(),
+                        status
+                    )
+                    .as_str(),
+                )
+                .context_value(
+                    "conflict_name".to_string(),
+                    Value::String(error.name_ref().to_string()),
+                ));
+        }
+        match self
+            .entries
+            .get_mut(&(error.code_ref().len(), error.code_ref().to_string()))
+            .filter(|x| x.name == error.name_ref())
+        {
+            Some(entry) => {
+                entry.status = status;
+                OK(())
+            }
+            None => Err(ERR_REGISTRY
+                .msg_detail(format!("错误[{}]尚未注册", error.name_ref()).as_str())
+                .context_value(
+                    "conflict_name".to_string(),
+                    Value::String(error.name_ref().to_string()),
+                )),
+        }
+    }
+
+    /// 注册错误信息并指定状态码，失败时记录到冲突列表中而不返回异常
+    pub(super) fn define(
+        &mut self,
+        tier: ErrorTier,
+        module: &str,
+        error: &AppError,
+        status: Option<u16>,
+    ) {
+        let res = self
+            .register(tier, module, error)
+            .and_then(|_| match status {
+                Some(v) => self.set_status(error, v),
+                None => OK(()),
+            });
+        if let Err(e) = res {
+            self.conflicts.push(e);
+        }
+    }
+
+    /// 通过define_errors!定义时注册失败的错误
+    pub fn conflicts(&self) -> &[AppError] {
+        &self.conflicts
+    }
+
+    /// 检查通过define_errors!定义的错误是否均已注册成功，存在冲突时返回ERR_REGISTRY异常
+    pub fn validate(&self) -> Result<()> {
+        if self.conflicts.is_empty() {
+            return OK(());
+        }
+        let details: Vec<&str> = self
+            .conflicts
+            .iter()
+            .filter_map(|x| x.msg_detail_ref())
+            .collect();
+        Err(ERR_REGISTRY.msg_detail(
+            format!(
+                "存在{}个错误注册失败：{}",
+                self.conflicts.len(),
+                details.join("；")
+            )
+            .as_str(),
+        ))
+    }
+
+    pub fn get_by_code(&self, code: &str) -> Option<&ErrorEntry> {
+        self.entries.get(&(code.len(), code.to_string()))
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&ErrorEntry> {
+        self.names.get(name).and_then(|code| self.get_by_code(code))
+    }
+
+    /// 按错误码顺序遍历已注册的错误信息
+    pub fn entries(&self) -> impl Iterator<Item = &ErrorEntry> {
+        self.entries.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 导出Json格式的错误码清单
+    pub fn to_json(&self) -> Result<String> {
+        OK(serde_json::to_string_pretty(
+            &self.entries().collect::<Vec<_>>(),
+        )?)
+    }
+
+    /// 导出Yaml格式的错误码清单
+    pub fn to_yaml(&self) -> Result<String> {
+        OK(serde_yaml::to_string(&self.entries().collect::<Vec<_>>())?)
+    }
+
+    /// 导出Markdown表格格式的错误码清单
+    pub fn to_markdown(&self) -> String {
+        let mut res = String::from("| 错误码 | 错误名称 | 错误信息 | 级别 | HTTP状态码 | 模块 |\n");
+        res.push_str("| --- | --- | --- | --- | --- | --- |\n");
+        for entry in self.entries() {
+            res.push_str(
+                format!(
+                    "| {} | {} | {} | {} | {} | {} |\n",
+                    entry.code,
+                    entry.name,
+                    entry.msg.replace('|', "\\|"),
+                    entry.tier,
+                    entry.status,
+                    entry.module
+                )
+                .as_str(),
+            );
+        }
+        res
+    }
+}
+
+/// 将错误信息注册到全局注册表中
+pub fn register_error(tier: ErrorTier, module: &str, error: &AppError) -> Result<()> {
+    GLOBAL_REGISTRY
+        .write()
+        .unwrap()
+        .register(tier, module, error)
+}
+
+/// 指定全局注册表中的错误转换为HTTP响应时使用的状态码
+pub fn set_error_status(error: &AppError, status: u16) -> Result<()> {
+    GLOBAL_REGISTRY.write().unwrap().set_status(error, status)
+}
+
+/// 供define_errors!在程序启动时注册错误，注册失败时记录到全局注册表的冲突列表中
+#[doc(hidden)]
+pub fn define_error(tier: ErrorTier, module: &str, error: &AppError, status: Option<u16>) {
+    GLOBAL_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .define(tier, module, error, status)
+}
+
+/// 获取全局注册表
+pub fn error_registry() -> RwLockReadGuard<'static, ErrorRegistry> {
+    GLOBAL_REGISTRY.read().unwrap()
+}
@@ -1,10 +1,30 @@
 #[cfg(test)]
 mod tests {
-    use std::fs::File;
+    use std::{fmt::Display, fs::File, rc::Rc};
 
-    use crate::error::{ERR_CAST, ERR_INTERNAL};
+    use futures::executor::block_on;
+    use hyper::{body::to_bytes, header::CONTENT_TYPE, Body, Response, StatusCode};
+
+    use crate::{
+        define_errors,
+        error::{
+            error_registry, negotiate_locale, set_message_catalog, AppError, ErrorEntry,
+            ErrorOrigin, ErrorRegistry, ErrorTier, MessageCatalog, ResponseOptions, ERR_ARGUMENT,
+            ERR_CAST, ERR_DATA, ERR_DB_ACTION, ERR_INTERNAL, ERR_IO, ERR_VALIDATION,
+            TRACE_ID_HEADER,
+        },
+        Value,
+    };
+
+    define_errors! {
+        Application;
+
+        /// 测试用错误
+        ERR_TEST_REGISTRY = ("900000001", "测试用错误", 404);
+    }
 
     #[test]
+    #[cfg_attr(miri, ignore)]
     fn test() {
         let err = File::open("not_exist.txt").err().unwrap();
         let app_err = ERR_CAST.msg_detail("other").cause(err);
@@ -17,4 +37,366 @@ mod tests {
         let app_err_str2 = format!("{:?}", app_err2);
         assert!(app_err_str2.contains("unknown"));
     }
+
+    /// 未实现Send及Sync的错误类型
+    #[derive(Debug)]
+    struct TrackedError {
+        _guard: Rc<()>,
+    }
+
+    impl Display for TrackedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "tracked")
+        }
+    }
+
+    impl std::error::Error for TrackedError {}
+
+    #[test]
+    fn test_owned_data() {
+        let err = {
+            let detail = format!("参数[{}]格式错误", "id");
+            ERR_DATA.msg_detail(detail.as_str())
+        };
+        // 覆盖已释放的临时字符串所在内存
+        let _other = "x".repeat(32);
+        assert_eq!(err.msg_detail_ref(), Some("参数[id]格式错误"));
+        assert_eq!(err.name_ref(), "ERR_DATA");
+
+        let name = String::from("ERR_CUSTOM");
+        let custom = AppError::new(name.clone(), "100000001", format!("自定义{}", "异常"));
+        drop(name);
+        assert_eq!(custom.name_ref(), "ERR_CUSTOM");
+        assert_eq!(custom.msg_ref(), "自定义异常");
+    }
+
+    #[test]
+    fn test_no_aliasing() {
+        let first = ERR_DATA.context_value("a".to_string(), Value::I32(1));
+        let second = first.context_value("b".to_string(), Value::I32(2));
+        let third = first.msg_detail("third");
+        assert_eq!(first.context_map_ref().unwrap().len(), 1);
+        assert_eq!(second.context_map_ref().unwrap().len(), 2);
+        assert_eq!(third.context_map_ref().unwrap().len(), 1);
+        assert!(first.msg_detail_ref().is_none());
+        assert!(ERR_DATA.context_map_ref().is_none());
+        assert!(ERR_DATA.cause_ref().is_none());
+    }
+
+    #[test]
+    fn test_drop() {
+        let token = Rc::new(());
+        let err = ERR_INTERNAL.cause(TrackedError {
+            _guard: token.clone(),
+        });
+        // 错误原因保存为快照，原始错误不会被保留
+        assert_eq!(Rc::strong_count(&token), 1);
+        let copy = err.clone();
+        assert_eq!(copy.cause_ref().unwrap().to_string(), "tracked");
+        assert!(format!("{:?}", copy.cause_ref().unwrap()).contains("TrackedError"));
+        drop(err);
+        assert_eq!(copy.cause_ref().unwrap().to_string(), "tracked");
+
+        let nested = ERR_DATA.cause(copy);
+        let cause = nested
+            .cause_ref()
+            .unwrap()
+            .downcast_ref::<AppError>()
+            .unwrap();
+        assert_eq!(cause, &*ERR_INTERNAL);
+        assert_eq!(cause.cause_ref().unwrap().to_string(), "tracked");
+
+        let handle = std::thread::spawn(move || nested.cause_ref().map(|x| x.to_string()));
+        assert!(handle.join().unwrap().is_some());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_backtrace() {
+        let err = ERR_DATA.backtrace();
+        assert!(err.backtrace_ref().is_some());
+        assert!(err.clone().backtrace_ref().is_some());
+        assert!(ERR_DATA.backtrace_ref().is_none());
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = ErrorRegistry::new();
+        let user = AppError::new("ERR_USER", "1000001", "用户异常");
+        registry
+            .register(ErrorTier::Project, "app::user", &user)
+            .unwrap();
+        registry
+            .register(ErrorTier::Framework, "app::base", &ERR_DATA)
+            .unwrap();
+        assert_eq!(registry.len(), 2);
+
+        let err = registry
+            .register(ErrorTier::Project, "app::other", &user)
+            .unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_REGISTRY");
+        assert!(err.msg_detail_ref().unwrap().contains("app::user"));
+        let err = registry
+            .register(
+                ErrorTier::Project,
+                "app::other",
+                &AppError::new("ERR_OTHER", "1000001", "其他异常"),
+            )
+            .unwrap_err();
+        assert!(err.msg_detail_ref().unwrap().contains("错误码[1000001]"));
+        let invalid = [
+            (ErrorTier::Application, "1000002"),
+            (ErrorTier::Framework, "1000002"),
+            (ErrorTier::Project, "10000a2"),
+            (ErrorTier::Project, ""),
+        ];
+        for (tier, code) in invalid {
+            let error = AppError::new("ERR_INVALID", code, "");
+            assert!(registry.register(tier, "app", &error).is_err());
+        }
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get_by_name("ERR_USER").unwrap().code, "1000001");
+
+        let entries: Vec<ErrorEntry> = serde_json::from_str(&registry.to_json().unwrap()).unwrap();
+        assert_eq!(entries[0].name, "ERR_DATA");
+        assert_eq!(entries[1].tier, ErrorTier::Project);
+        let entries: Vec<ErrorEntry> = serde_yaml::from_str(&registry.to_yaml().unwrap()).unwrap();
+        assert_eq!(entries, registry.entries().cloned().collect::<Vec<_>>());
+        let markdown = registry.to_markdown();
+        assert_eq!(markdown.lines().count(), 4);
+        assert!(markdown.contains("| 1000001 | ERR_USER | 用户异常 | project | 500 | app::user |"));
+
+        let err = registry
+            .register(ErrorTier::Project, "app::other", &user)
+            .unwrap_err();
+        assert_eq!(
+            err.context_map_ref().unwrap().get("conflict_name"),
+            Some(&Value::String("ERR_USER".to_string()))
+        );
+        assert!(registry.validate().is_ok());
+        registry.define(ErrorTier::Project, "app::other", &user, None);
+        registry.define(ErrorTier::Framework, "app::base", &ERR_CAST, Some(1000));
+        assert_eq!(registry.conflicts().len(), 2);
+        let err = registry.validate().unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_REGISTRY");
+        assert!(err
+            .msg_detail_ref()
+            .unwrap()
+            .contains("存在2个错误注册失败"));
+        assert!(registry.get_by_name("ERR_CAST").is_some());
+
+        let global = error_registry();
+        assert!(global.validate().is_ok());
+        let entry = global.get_by_name("ERR_INTERNAL").unwrap();
+        assert_eq!(entry.tier, ErrorTier::Framework);
+        assert_eq!(entry.module, "knife_util::error::constant");
+        let entry = global.get_by_code("900000001").unwrap();
+        assert_eq!(entry.name, ERR_TEST_REGISTRY.name_ref());
+        assert_eq!(entry.tier, ErrorTier::Application);
+        assert_eq!(entry.status, 404);
+    }
+
+    #[test]
+    fn test_http_response() {
+        assert_eq!(ERR_ARGUMENT.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            ERR_VALIDATION.status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(ERR_DATA.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(ERR_TEST_REGISTRY.status_code(), StatusCode::NOT_FOUND);
+        let other = AppError::new("ERR_OTHER", "900000001", "");
+        assert_eq!(other.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let mut registry = ErrorRegistry::new();
+        registry
+            .register(ErrorTier::Framework, "app", &ERR_DATA)
+            .unwrap();
+        assert!(registry.set_status(&ERR_DATA, 1000).is_err());
+        assert!(registry.set_status(&ERR_CAST, 400).is_err());
+        registry.set_status(&ERR_DATA, 409).unwrap();
+        assert_eq!(registry.get_by_name("ERR_DATA").unwrap().status, 409);
+
+        let err = ERR_VALIDATION
+            .msg_detail("参数[id]不能为空")
+            .context_value("field".to_string(), Value::String("id".to_string()))
+            .cause(File::open("not_exist.txt").err().unwrap());
+        let response = err
+            .clone()
+            .into_hyper_response_with(&ResponseOptions::new().debug(false).trace_id("t-001"));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers()[CONTENT_TYPE],
+            "application/json; charset=utf-8"
+        );
+        assert_eq!(response.headers()[TRACE_ID_HEADER], "t-001");
+        assert_eq!(response.headers()["X-Trace-Id"], "t-001");
+        let body = block_on(to_bytes(response.into_body())).unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["name"], "ERR_VALIDATION");
+        assert_eq!(body["msg_detail"], "参数[id]不能为空");
+        assert_eq!(body["field"], "id");
+        assert!(body.get("cause").is_none());
+        assert!(body.get("backtrace").is_none());
+
+        let response =
+            err.into_hyper_response_with(&ResponseOptions::new().debug(true).trace_id("a\nb"));
+        assert!(response.headers().get(TRACE_ID_HEADER).is_none());
+        let parsed = block_on(AppError::from_hyper_response(response));
+        assert_eq!(parsed.name_ref(), "ERR_VALIDATION");
+        assert_eq!(parsed.code_ref(), ERR_VALIDATION.code_ref());
+        assert_eq!(parsed.msg_ref(), ERR_VALIDATION.msg_ref());
+        assert_eq!(parsed.msg_detail_ref(), Some("参数[id]不能为空"));
+        assert!(parsed.cause_ref().unwrap().to_string().contains("NotFound"));
+        assert_eq!(
+            parsed.context_map_ref().unwrap().get("field"),
+            Some(&Value::String("id".to_string()))
+        );
+
+        assert_eq!(
+            AppError::from_json_str("[1]").unwrap_err().name_ref(),
+            "ERR_PARSE"
+        );
+        assert!(AppError::from_json_str(r#"{"name": "ERR_X"}"#).is_err());
+        let conflict =
+            ERR_DATA.context_value("name".to_string(), Value::String("ERR_B".to_string()));
+        let body: serde_json::Value = serde_json::from_str(&conflict.to_json_string()).unwrap();
+        assert_eq!(body["name"], "ERR_DATA");
+        assert!(body["msg_detail"].is_null());
+        let parsed = AppError::from_json_str(&body.to_string()).unwrap();
+        assert_eq!(parsed, *ERR_DATA);
+        assert!(parsed.context_map_ref().is_none());
+        let mut upstream = Response::new(Body::from("Service Unavailable"));
+        *upstream.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+        let err = block_on(AppError::from_hyper_response(upstream));
+        assert_eq!(err.name_ref(), "ERR_WEB");
+        assert_eq!(
+            err.context_map_ref().unwrap().get("status"),
+            Some(&Value::U32(503))
+        );
+    }
+
+    #[test]
+    fn test_wire() {
+        let io_err = File::open("not_exist.txt").err().unwrap();
+        let io_msg = io_err.to_string();
+        let err = ERR_DB_ACTION
+            .msg_detail("查询用户失败")
+            .context_value("table".to_string(), Value::String("user".to_string()))
+            .cause(ERR_IO.msg_detail("连接超时").cause(io_err))
+            .origin(ErrorOrigin::new("user-service").trace_id("t-001"))
+            .backtrace();
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["name"], "ERR_DB_ACTION");
+        assert_eq!(json["context"]["table"], "user");
+        assert_eq!(json["origin"]["service"], "user-service");
+        assert_eq!(json["cause"]["name"], "ERR_IO");
+        assert_eq!(json["cause"]["cause"]["name"], "");
+        assert_eq!(json["cause"]["cause"]["msg"], io_msg.as_str());
+        assert!(json.get("backtrace").is_none());
+
+        let received: AppError = serde_json::from_str(&json.to_string()).unwrap();
+        assert_eq!(received, *ERR_DB_ACTION);
+        assert_ne!(received, *ERR_IO);
+        assert_eq!(received.msg_detail_ref(), Some("查询用户失败"));
+        assert_eq!(
+            received.context_map_ref().unwrap().get("table"),
+            Some(&Value::String("user".to_string()))
+        );
+        let origin = received.origin_ref().unwrap();
+        assert_eq!(origin.service.as_deref(), Some("user-service"));
+        assert_eq!(origin.trace_id.as_deref(), Some("t-001"));
+        let cause = received
+            .cause_ref()
+            .unwrap()
+            .downcast_ref::<AppError>()
+            .unwrap();
+        assert_eq!(cause, &*ERR_IO);
+        assert_eq!(cause.msg_detail_ref(), Some("连接超时"));
+        let root = cause.cause_ref().unwrap();
+        assert!(root.downcast_ref::<AppError>().is_none());
+        assert_eq!(root.to_string(), io_msg);
+        assert_eq!(serde_json::to_value(&received).unwrap(), json);
+
+        let minimal: AppError =
+            serde_yaml::from_str("name: ERR_DATA\ncode: '100007'\nmsg: ''").unwrap();
+        assert_eq!(minimal, *ERR_DATA);
+        assert!(minimal.cause_ref().is_none());
+        assert!(minimal.origin_ref().is_none());
+    }
+
+    #[test]
+    fn test_i18n() {
+        let mut catalog = MessageCatalog::new();
+        catalog
+            .add_yaml(
+                "en",
+                "ERR_VALIDATION:\n  msg: Validation failed\n  msg_detail: \"<{{field}}> needs {{min}} chars\"\nERR_IO: IO failed\n",
+            )
+            .unwrap();
+        catalog
+            .add_json("zh_CN", r#"{"ERR_VALIDATION": "参数校验失败"}"#)
+            .unwrap();
+        assert_eq!(
+            catalog.add_yaml("en", "- a").unwrap_err().name_ref(),
+            "ERR_PARSE"
+        );
+        assert_eq!(catalog.locales(), vec!["en", "zh-cn"]);
+
+        let err = ERR_VALIDATION
+            .msg_detail("参数[name]长度不足")
+            .context_value("field".to_string(), Value::String("name".to_string()))
+            .context_value("min".to_string(), Value::I32(3));
+        let en = err.localized_with(&catalog, "en-US");
+        assert_eq!(en, *ERR_VALIDATION);
+        assert_eq!(en.msg_ref(), "Validation failed");
+        assert_eq!(en.msg_detail_ref(), Some("<name> needs 3 chars"));
+        assert_eq!(en.context_map_ref().unwrap().len(), 2);
+        assert_eq!(err.msg_ref(), ERR_VALIDATION.msg_ref());
+        let zh = err.localized_with(&catalog, "zh-CN");
+        assert_eq!(zh.msg_ref(), "参数校验失败");
+        assert_eq!(zh.msg_detail_ref(), Some("参数[name]长度不足"));
+        let fr = err.localized_with(&catalog, "fr");
+        assert_eq!(fr.msg_ref(), ERR_VALIDATION.msg_ref());
+        assert_eq!(
+            ERR_DATA.localized_with(&catalog, "en").msg_ref(),
+            ERR_DATA.msg_ref()
+        );
+
+        assert_eq!(
+            catalog.negotiate("fr, zh-TW;q=0.8, en;q=0.5").as_deref(),
+            Some("zh-cn")
+        );
+        assert_eq!(
+            negotiate_locale("de, *;q=0.1", &["en", "zh"]).as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            negotiate_locale("en-GB;q=0.2, zh_CN", &["en", "zh-CN"]).as_deref(),
+            Some("zh-CN")
+        );
+        assert!(negotiate_locale("en;q=0, de", &["en"]).is_none());
+        assert_eq!(
+            negotiate_locale("en;q=abc, zh;q=0.5", &["en", "zh"]).as_deref(),
+            Some("zh")
+        );
+        assert!(negotiate_locale("", &["en"]).is_none());
+
+        let dir = std::env::temp_dir().join(format!("knife_util_i18n_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("ja.json"), r#"{"ERR_IO": "IOエラー"}"#).unwrap();
+        std::fs::write(dir.join("readme.txt"), "ignored").unwrap();
+        catalog.add_dir(&dir).unwrap();
+        assert_eq!(catalog.get("ja", "ERR_IO").unwrap().msg(), "IOエラー");
+        assert!(catalog.add_file("en", dir.join("readme.txt")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        set_message_catalog(catalog);
+        assert_eq!(err.localized("en").msg_ref(), "Validation failed");
+        let response = err.into_hyper_response_with(&ResponseOptions::new().locale("en"));
+        let body = block_on(to_bytes(response.into_body())).unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["msg"], "Validation failed");
+        assert_eq!(body["msg_detail"], "<name> needs 3 chars");
+    }
 }
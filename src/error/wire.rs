@@ -0,0 +1,170 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Value;
+
+use super::AppError;
+
+/// 错误的来源，用于跨服务传递错误时定位出错的服务及请求
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorOrigin {
+    /// 出错的服务名称
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+
+    /// 出错请求的链路追踪标识
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+}
+
+impl ErrorOrigin {
+    pub fn new(service: &str) -> Self {
+        ErrorOrigin {
+            service: Some(service.to_string()),
+            trace_id: None,
+        }
+    }
+
+    pub fn trace_id(mut self, value: &str) -> Self {
+        self.trace_id = Some(value.to_string());
+        self
+    }
+}
+
+/// 远程服务传递的非AppError类型的错误原因，仅保留错误信息及其内部原因
+pub(super) struct RemoteCause {
+    msg: String,
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl RemoteCause {
+    pub(super) fn new(msg: String) -> Self {
+        RemoteCause { msg, source: None }
+    }
+}
+
+impl std::fmt::Debug for RemoteCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.msg.as_str())
+    }
+}
+
+impl Display for RemoteCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.msg.as_str())
+    }
+}
+
+impl std::error::Error for RemoteCause {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|x| x as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// AppError的传输格式
+///
+/// 错误原因按链式结构逐层嵌套，非AppError类型的错误原因name及code均为空，仅保留错误信息
+#[derive(Serialize, Deserialize)]
+struct WireError {
+    name: String,
+    code: String,
+    msg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    msg_detail: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    context: BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cause: Option<Box<WireError>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    origin: Option<ErrorOrigin>,
+}
+
+impl WireError {
+    fn from_error(error: &AppError) -> Self {
+        WireError {
+            name: error.name_ref().to_string(),
+            code: error.code_ref().to_string(),
+            msg: error.msg_ref().to_string(),
+            msg_detail: error.msg_detail_ref().map(|x| x.to_string()),
+            context: error
+                .context_map_ref()
+                .map(|x| x.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+            cause: error
+                .cause_ref()
+                .map(|x| Box::new(WireError::from_cause(x))),
+            origin: error.origin_ref().cloned(),
+        }
+    }
+
+    fn from_cause(cause: &(dyn std::error::Error + 'static)) -> Self {
+        if let Some(error) = cause.downcast_ref::<AppError>() {
+            return WireError::from_error(error);
+        }
+        WireError {
+            name: String::new(),
+            code: String::new(),
+            msg: cause.to_string(),
+            msg_detail: None,
+            context: BTreeMap::new(),
+            cause: cause.source().map(|x| Box::new(WireError::from_cause(x))),
+            origin: None,
+        }
+    }
+
+    fn into_error(self) -> AppError {
+        let mut error = AppError::new(self.name, self.code, self.msg);
+        if let Some(detail) = self.msg_detail {
+            error = error.msg_detail(detail.as_str());
+        }
+        for (k, v) in self.context {
+            error = error.context_value(k, v);
+        }
+        if let Some(cause) = self.cause {
+            error = match cause.into_cause() {
+                Ok(v) => error.cause(v),
+                Err(v) => error.cause(v),
+            };
+        }
+        if let Some(origin) = self.origin {
+            error = error.origin(origin);
+        }
+        error
+    }
+
+    fn into_cause(self) -> std::result::Result<AppError, RemoteCause> {
+        if !self.name.is_empty() || !self.code.is_empty() {
+            return Ok(self.into_error());
+        }
+        Err(RemoteCause {
+            msg: self.msg,
+            source: self.cause.map(|x| match x.into_cause() {
+                Ok(v) => Box::new(v) as Box<dyn std::error::Error + Send + Sync>,
+                Err(v) => Box::new(v),
+            }),
+        })
+    }
+}
+
+/// 按传输格式序列化，不包含堆栈信息
+impl Serialize for AppError {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        WireError::from_error(self).serialize(serializer)
+    }
+}
+
+/// 按传输格式反序列化，嵌套的错误原因还原为AppError，非AppError类型的错误原因仅保留错误信息
+impl<'de> Deserialize<'de> for AppError {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        WireError::deserialize(deserializer).map(WireError::into_error)
+    }
+}
@@ -1,23 +1,34 @@
 //! 通用工具类
+extern crate self as knife_util;
+
 pub mod any;
 pub mod bean;
+pub mod config;
 pub mod context;
 pub mod date;
 pub mod error;
 pub mod future;
 pub mod iter;
 pub mod page;
+pub mod patch;
+pub mod query;
 pub mod template;
+pub mod validation;
+pub mod visit;
 
 pub mod types;
 pub use types::{Result, ResultExt, OK};
 
 mod value_type;
-pub use value_type::Value;
+pub use value_type::{
+    from_value, to_value, ArrayStyle, CoercionPolicy, CoercionRule, SharedValue, Value,
+    ValueBuilder,
+};
 
 /// Reexport
 pub mod crates {
     pub use async_trait;
+    pub use clap;
     pub use ctor;
     pub use futures;
     pub use hyper;
@@ -26,6 +37,7 @@ pub mod crates {
     pub use rbatis;
     pub use rbdc_pg;
     pub use rbs;
+    pub use rust_decimal;
     pub use tokio;
     pub use tracing_core;
     pub use tracing_opentelemetry;
@@ -37,4 +49,5 @@ pub mod crates_builtin {
     pub use chrono;
     pub use serde_json;
     pub use serde_yaml;
+    pub use toml;
 }
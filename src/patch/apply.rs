@@ -0,0 +1,102 @@
+use crate::{
+    bean::{parse_insert_index, PointerMutTrait},
+    error::ERR_MERGE,
+    Result, Value, OK,
+};
+
+use super::model::{Patch, PatchOperation};
+
+/// 将补丁按顺序应用到Value对象上
+///
+/// 任意一个操作失败时将返回ERR_MERGE异常，上下文中的index为失败操作的下标，
+/// 此时原对象保持不变
+pub fn apply_patch(target: &mut Value, patch: &Patch) -> Result<()> {
+    let mut res = target.clone();
+    for (i, op) in patch.iter().enumerate() {
+        apply_operation(&mut res, op).map_err(|e| {
+            ERR_MERGE
+                .msg_detail(
+                    format!(
+                        "第{}个补丁操作[{} {}]执行失败：{}",
+                        i,
+                        op.op_name(),
+                        op.path(),
+                        e.msg_detail_ref().unwrap_or_else(|| e.msg_ref())
+                    )
+                    .as_str(),
+                )
+                .context_value("index".to_string(), Value::U64(i as u64))
+                .context_value("op".to_string(), Value::String(op.op_name().to_string()))
+                .context_value("path".to_string(), Value::String(op.path().to_string()))
+        })?;
+    }
+    *target = res;
+    OK(())
+}
+
+fn apply_operation(target: &mut Value, op: &PatchOperation) -> Result<()> {
+    match op {
+        PatchOperation::Add { path, value } => add(target, path, value.clone()),
+        PatchOperation::Remove { path } => remove(target, path).map(|_| ()),
+        PatchOperation::Replace { path, value } => {
+            *get_mut(target, path)? = value.clone();
+            OK(())
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(format!("{}/", from).as_str()) {
+                return Err(ERR_MERGE.msg_detail("不能将数据移动到其自身的子节点中"));
+            }
+            if from == path {
+                return get_mut(target, from).map(|_| ());
+            }
+            let value = remove(target, from)?;
+            add(target, path, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = get_mut(target, from)?.clone();
+            add(target, path, value)
+        }
+        PatchOperation::Test { path, value } => {
+            if get_mut(target, path)? == value {
+                OK(())
+            } else {
+                Err(ERR_MERGE.msg_detail("数据与期望值不一致"))
+            }
+        }
+    }
+}
+
+fn get_mut<'a>(target: &'a mut Value, pointer: &str) -> Result<&'a mut Value> {
+    target
+        .p_mut(pointer)
+        .ok_or_else(|| ERR_MERGE.msg_detail(format!("路径[{}]不存在", pointer).as_str()))
+}
+
+fn add(target: &mut Value, pointer: &str, value: Value) -> Result<()> {
+    if pointer.is_empty() {
+        *target = value;
+        return OK(());
+    }
+    let (parent, last) = match pointer.rfind('/') {
+        Some(i) => (&pointer[..i], &pointer[i + 1..]),
+        None => return Err(ERR_MERGE.msg_detail(format!("路径[{}]需以/开头", pointer).as_str())),
+    };
+    match get_mut(target, parent)? {
+        Value::Object(map) => {
+            map.insert(last.replace("~1", "/").replace("~0", "~"), value);
+            OK(())
+        }
+        Value::Array(list) => {
+            let index = parse_insert_index(last, list.len())?;
+            list.insert(index, value);
+            OK(())
+        }
+        _ => Err(ERR_MERGE.msg_detail("上级节点不是容器类型")),
+    }
+}
+
+fn remove(target: &mut Value, pointer: &str) -> Result<Value> {
+    target
+        .remove_by_pointer(pointer)?
+        .ok_or_else(|| ERR_MERGE.msg_detail(format!("路径[{}]不存在", pointer).as_str()))
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use crate::{bean::escape_token, Value};
+
+use super::model::{Patch, PatchOperation};
+
+/// 比较两个Value对象，生成将source转换为target的补丁
+///
+/// Object按键逐层比较，Array按下标逐个比较，多出的元素从尾部依次删除或追加，类型不同时直接替换
+pub fn diff(source: &Value, target: &Value) -> Patch {
+    let mut ops = vec![];
+    diff_value("", source, target, &mut ops);
+    Patch(ops)
+}
+
+fn diff_value(path: &str, source: &Value, target: &Value, ops: &mut Vec<PatchOperation>) {
+    match (source, target) {
+        (Value::Object(a), Value::Object(b)) => diff_object(path, a, b, ops),
+        (Value::Array(a), Value::Array(b)) => diff_array(path, a, b, ops),
+        (a, b) => {
+            if a != b {
+                ops.push(PatchOperation::Replace {
+                    path: path.to_string(),
+                    value: b.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_object(
+    path: &str,
+    source: &BTreeMap<String, Value>,
+    target: &BTreeMap<String, Value>,
+    ops: &mut Vec<PatchOperation>,
+) {
+    for (k, v) in source {
+        let child = format!("{}/{}", path, escape_token(k));
+        match target.get(k) {
+            Some(v2) => diff_value(child.as_str(), v, v2, ops),
+            None => ops.push(PatchOperation::Remove { path: child }),
+        }
+    }
+    for (k, v) in target {
+        if !source.contains_key(k) {
+            ops.push(PatchOperation::Add {
+                path: format!("{}/{}", path, escape_token(k)),
+                value: v.clone(),
+            });
+        }
+    }
+}
+
+fn diff_array(path: &str, source: &[Value], target: &[Value], ops: &mut Vec<PatchOperation>) {
+    let common = source.len().min(target.len());
+    for i in 0..common {
+        diff_value(
+            format!("{}/{}", path, i).as_str(),
+            &source[i],
+            &target[i],
+            ops,
+        );
+    }
+    for i in (common..source.len()).rev() {
+        ops.push(PatchOperation::Remove {
+            path: format!("{}/{}", path, i),
+        });
+    }
+    for v in &target[common..] {
+        ops.push(PatchOperation::Add {
+            path: format!("{}/-", path),
+            value: v.clone(),
+        });
+    }
+}
@@ -0,0 +1,12 @@
+//! RFC6902 JSON Patch工具类
+//!
+//! 支持比较两个内置Value对象生成补丁，以及将补丁应用到内置Value对象上
+//! 更多信息可参考：[RFC6902](https://tools.ietf.org/html/rfc6902)
+mod apply;
+mod diff;
+mod model;
+mod tests;
+
+pub use apply::apply_patch;
+pub use diff::diff;
+pub use model::{Patch, PatchOperation};
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Value;
+
+/// 补丁操作，序列化格式同RFC6902中的定义
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    /// 在指定位置添加数据
+    Add { path: String, value: Value },
+    /// 删除指定位置的数据
+    Remove { path: String },
+    /// 替换指定位置的数据
+    Replace { path: String, value: Value },
+    /// 将from位置的数据移动到path位置
+    Move { from: String, path: String },
+    /// 将from位置的数据复制到path位置
+    Copy { from: String, path: String },
+    /// 检查指定位置的数据是否与value相同
+    Test { path: String, value: Value },
+}
+
+impl PatchOperation {
+    /// 操作名称，如：add
+    pub fn op_name(&self) -> &'static str {
+        match self {
+            PatchOperation::Add { .. } => "add",
+            PatchOperation::Remove { .. } => "remove",
+            PatchOperation::Replace { .. } => "replace",
+            PatchOperation::Move { .. } => "move",
+            PatchOperation::Copy { .. } => "copy",
+            PatchOperation::Test { .. } => "test",
+        }
+    }
+
+    /// 操作的目标位置
+    pub fn path(&self) -> &str {
+        match self {
+            PatchOperation::Add { path, .. } => path,
+            PatchOperation::Remove { path } => path,
+            PatchOperation::Replace { path, .. } => path,
+            PatchOperation::Move { path, .. } => path,
+            PatchOperation::Copy { path, .. } => path,
+            PatchOperation::Test { path, .. } => path,
+        }
+    }
+}
+
+/// 补丁，由多个补丁操作按顺序组成
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct Patch(pub Vec<PatchOperation>);
+
+impl Patch {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PatchOperation> {
+        self.0.iter()
+    }
+}
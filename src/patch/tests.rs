@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        bean::{AsValueTrait, FromValueTrait},
+        patch::{apply_patch, diff, Patch},
+        Value,
+    };
+
+    fn value(v: serde_json::Value) -> Value {
+        v.as_value().unwrap()
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        let mut target = value(json!({
+            "name": "app",
+            "tags": ["a", "b"],
+            "server": {"port": 8080}
+        }));
+        let patch: Patch = serde_json::from_value(json!([
+            {"op": "test", "path": "/name", "value": "app"},
+            {"op": "replace", "path": "/server/port", "value": 9090},
+            {"op": "add", "path": "/tags/1", "value": "c"},
+            {"op": "add", "path": "/tags/-", "value": "d"},
+            {"op": "remove", "path": "/tags/0"},
+            {"op": "copy", "from": "/server", "path": "/backup"},
+            {"op": "move", "from": "/name", "path": "/title"}
+        ]))
+        .unwrap();
+        apply_patch(&mut target, &patch).unwrap();
+        let res = serde_json::Value::from_value(&target).unwrap();
+        assert_eq!(
+            res,
+            json!({
+                "title": "app",
+                "tags": ["c", "b", "d"],
+                "server": {"port": 9090},
+                "backup": {"port": 9090}
+            })
+        );
+
+        let patch: Patch = serde_json::from_value(json!([
+            {"op": "remove", "path": "/title"},
+            {"op": "test", "path": "/server/port", "value": 1}
+        ]))
+        .unwrap();
+        let err = apply_patch(&mut target, &patch).unwrap_err();
+        let index = err.context_map_ref().unwrap().get("index").unwrap();
+        assert_eq!(index.as_u64().unwrap(), 1);
+        assert!(target.as_object().unwrap().contains_key("title"));
+
+        for path in ["/tags/+1", "/tags/01", "/tags/9"] {
+            let patch: Patch =
+                serde_json::from_value(json!([{"op": "add", "path": path, "value": "e"}])).unwrap();
+            assert!(apply_patch(&mut target, &patch).is_err());
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let source = value(json!({
+            "a": 1,
+            "b": {"c": [1, 2, 3], "d": "x"},
+            "e~/f": true
+        }));
+        let target = value(json!({
+            "a": 2,
+            "b": {"c": [1, 5], "g": null},
+            "h": [1]
+        }));
+        let patch = diff(&source, &target);
+        let json = serde_json::to_value(&patch).unwrap();
+        assert!(json
+            .as_array()
+            .unwrap()
+            .contains(&json!({"op": "remove", "path": "/e~0~1f"})));
+
+        let mut res = source.clone();
+        apply_patch(&mut res, &patch).unwrap();
+        assert!(diff(&res, &target).is_empty());
+        assert_eq!(
+            serde_json::Value::from_value(&res).unwrap(),
+            serde_json::Value::from_value(&target).unwrap()
+        );
+    }
+}
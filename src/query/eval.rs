@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+
+use crate::{
+    bean::escape_token,
+    date::{Date, DateTime, Time, YearMonth},
+    value_type::value_cmp,
+    Result, Value, OK,
+};
+
+use super::model::{CompareOp, Expr, JsonPath, Operand, Segment, Selector};
+
+/// 查询命中的节点
+#[derive(Debug, Clone)]
+pub struct QueryMatch<'a> {
+    /// 节点对应的RFC6901路径，可直接用于PointerTrait及JSON Patch
+    pub pointer: String,
+    /// 节点数据
+    pub value: &'a Value,
+}
+
+/// 使用JSONPath表达式在指定对象中查询所有匹配的节点
+///
+/// 需多次使用同一表达式时，可通过JsonPath::parse预先解析
+pub fn query<'a>(value: &'a Value, expr: &str) -> Result<Vec<QueryMatch<'a>>> {
+    OK(JsonPath::parse(expr)?.query(value))
+}
+
+pub(super) fn select<'a>(
+    segments: &[Segment],
+    root: &'a Value,
+    current: &'a Value,
+    pointer: String,
+    res: &mut Vec<QueryMatch<'a>>,
+) {
+    let (segment, rest) = match segments.split_first() {
+        Some(v) => v,
+        None => {
+            res.push(QueryMatch {
+                pointer,
+                value: current,
+            });
+            return;
+        }
+    };
+    match segment {
+        Segment::Child(selectors) => {
+            for (p, v) in select_children(selectors, root, current, &pointer) {
+                select(rest, root, v, p, res);
+            }
+        }
+        Segment::Descendant(selectors) => {
+            let mut nodes = vec![];
+            descendants(current, pointer, &mut nodes);
+            for (node_pointer, node) in nodes {
+                for (p, v) in select_children(selectors, root, node, &node_pointer) {
+                    select(rest, root, v, p, res);
+                }
+            }
+        }
+    }
+}
+
+/// 按文档顺序收集当前节点及其所有后代节点
+fn descendants<'a>(value: &'a Value, pointer: String, res: &mut Vec<(String, &'a Value)>) {
+    res.push((pointer.clone(), value));
+    match value {
+        Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                descendants(v, format!("{}/{}", pointer, i), res);
+            }
+        }
+        Value::Object(obj) => {
+            for (k, v) in obj {
+                descendants(v, format!("{}/{}", pointer, escape_token(k)), res);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn select_children<'a>(
+    selectors: &[Selector],
+    root: &'a Value,
+    current: &'a Value,
+    pointer: &str,
+) -> Vec<(String, &'a Value)> {
+    let mut res = vec![];
+    for selector in selectors {
+        match (selector, current) {
+            (Selector::Name(name), Value::Object(obj)) => {
+                if let Some(v) = obj.get(name) {
+                    res.push((format!("{}/{}", pointer, escape_token(name)), v));
+                }
+            }
+            (Selector::Index(index), Value::Array(arr)) => {
+                let i = if *index < 0 {
+                    arr.len() as i64 + index
+                } else {
+                    *index
+                };
+                if i >= 0 && (i as usize) < arr.len() {
+                    res.push((format!("{}/{}", pointer, i), &arr[i as usize]));
+                }
+            }
+            (Selector::Slice(start, end, step), Value::Array(arr)) => {
+                for i in slice_indexes(arr.len() as i64, *start, *end, *step) {
+                    res.push((format!("{}/{}", pointer, i), &arr[i]));
+                }
+            }
+            (Selector::Wildcard, _) | (Selector::Filter(_), _) => {
+                for (p, v) in children(current, pointer) {
+                    let matched = match selector {
+                        Selector::Filter(expr) => eval_expr(expr, root, v),
+                        _ => true,
+                    };
+                    if matched {
+                        res.push((p, v));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    res
+}
+
+fn children<'a>(value: &'a Value, pointer: &str) -> Vec<(String, &'a Value)> {
+    match value {
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("{}/{}", pointer, i), v))
+            .collect(),
+        Value::Object(obj) => obj
+            .iter()
+            .map(|(k, v)| (format!("{}/{}", pointer, escape_token(k)), v))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// 按照Python切片规则计算下标
+fn slice_indexes(len: i64, start: Option<i64>, end: Option<i64>, step: i64) -> Vec<usize> {
+    let normalize = |v: i64| if v < 0 { len + v } else { v };
+    let mut res = vec![];
+    if step > 0 {
+        let mut i = start.map(normalize).unwrap_or(0).clamp(0, len);
+        let end = end.map(normalize).unwrap_or(len).clamp(0, len);
+        while i < end {
+            res.push(i as usize);
+            i = match i.checked_add(step) {
+                Some(v) => v,
+                None => break,
+            };
+        }
+    } else {
+        let mut i = start.map(normalize).unwrap_or(len - 1).clamp(-1, len - 1);
+        let end = end.map(normalize).unwrap_or(-1).clamp(-1, len - 1);
+        while i > end {
+            res.push(i as usize);
+            i = match i.checked_add(step) {
+                Some(v) => v,
+                None => break,
+            };
+        }
+    }
+    res
+}
+
+fn eval_expr(expr: &Expr, root: &Value, current: &Value) -> bool {
+    match expr {
+        Expr::Or(a, b) => eval_expr(a, root, current) || eval_expr(b, root, current),
+        Expr::And(a, b) => eval_expr(a, root, current) && eval_expr(b, root, current),
+        Expr::Not(a) => !eval_expr(a, root, current),
+        Expr::Exists(operand) => !eval_operand(operand, root, current).is_empty(),
+        Expr::Compare(left, op, right) => {
+            let left = eval_operand(left, root, current);
+            let right = eval_operand(right, root, current);
+            // 路径需恰好命中一个节点才可参与比较，均未命中时视为相同
+            match (left.as_slice(), right.as_slice()) {
+                ([], []) => matches!(op, CompareOp::Eq | CompareOp::Le | CompareOp::Ge),
+                ([a], [b]) => compare(a, *op, b),
+                _ => *op == CompareOp::Ne,
+            }
+        }
+    }
+}
+
+fn eval_operand<'a>(operand: &'a Operand, root: &'a Value, current: &'a Value) -> Vec<&'a Value> {
+    let (segments, start) = match operand {
+        Operand::Literal(v) => return vec![v],
+        Operand::Current(segments) => (segments, current),
+        Operand::Root(segments) => (segments, root),
+    };
+    let mut res = vec![];
+    select(segments, root, start, String::new(), &mut res);
+    res.into_iter().map(|v| v.value).collect()
+}
+
+fn compare(a: &Value, op: CompareOp, b: &Value) -> bool {
+    let a = coerce_date(a, b);
+    let b = coerce_date(b, &a);
+    match op {
+        CompareOp::Eq => a == b,
+        CompareOp::Ne => a != b,
+        CompareOp::Lt => value_cmp(&a, &b) == Some(Ordering::Less),
+        CompareOp::Le => matches!(
+            value_cmp(&a, &b),
+            Some(Ordering::Less) | Some(Ordering::Equal)
+        ),
+        CompareOp::Gt => value_cmp(&a, &b) == Some(Ordering::Greater),
+        CompareOp::Ge => matches!(
+            value_cmp(&a, &b),
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        ),
+    }
+}
+
+/// 日期类型与字符串比较时，将字符串转换为对应的日期类型，转换失败时保持原值
+fn coerce_date(value: &Value, other: &Value) -> Value {
+    let s = match value {
+        Value::String(s) => s.as_str(),
+        _ => return value.clone(),
+    };
+    let res = match other {
+        Value::Date(_) => Date::parse_str(s).map(Value::Date).ok(),
+        Value::Time(_) => Time::parse_str(s).map(Value::Time).ok(),
+        Value::DateTime(_) => DateTime::parse_str(s).map(Value::DateTime).ok(),
+        Value::YearMonth(_) => YearMonth::parse_str(s).map(Value::YearMonth).ok(),
+        _ => None,
+    };
+    res.unwrap_or_else(|| value.clone())
+}
@@ -0,0 +1,19 @@
+//! JSONPath查询工具类
+//!
+//! 支持以$.orders[*].items[?(@.price > 10)].sku的方式在内置Value对象中查询多个节点，
+//! 并返回每个节点对应的RFC6901路径
+//!
+//! 支持的语法：
+//! - `$`：根节点，`@`：过滤表达式中的当前节点
+//! - `.name`、`['name']`：子节点，`[0]`、`[-1]`：数组下标，`[0,1]`：多个子节点
+//! - `*`、`[*]`：所有子节点，`..`：递归查找所有后代节点
+//! - `[start:end:step]`：数组切片
+//! - `[?(expr)]`：过滤表达式，支持==、!=、<、<=、>、>=、&&、||、!及括号，
+//!   日期类型可直接与日期格式字符串进行比较
+mod eval;
+mod model;
+mod parser;
+mod tests;
+
+pub use eval::{query, QueryMatch};
+pub use model::JsonPath;
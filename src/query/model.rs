@@ -0,0 +1,74 @@
+use crate::{Result, Value};
+
+use super::{
+    eval::{select, QueryMatch},
+    parser::Parser,
+};
+
+/// 解析后的JSONPath表达式，可重复用于查询多个对象
+#[derive(Debug, Clone)]
+pub struct JsonPath {
+    pub(super) segments: Vec<Segment>,
+}
+
+impl JsonPath {
+    /// 解析JSONPath表达式，表达式不合法时返回ERR_PARSE异常
+    pub fn parse(expr: &str) -> Result<JsonPath> {
+        Parser::new(expr).parse_path()
+    }
+
+    /// 在指定对象中查询所有匹配的节点，按文档顺序返回
+    pub fn query<'a>(&self, value: &'a Value) -> Vec<QueryMatch<'a>> {
+        let mut res = vec![];
+        select(&self.segments, value, value, String::new(), &mut res);
+        res
+    }
+}
+
+/// 路径中的一段
+#[derive(Debug, Clone)]
+pub(super) enum Segment {
+    /// 子节点
+    Child(Vec<Selector>),
+    /// 递归查找所有后代节点
+    Descendant(Vec<Selector>),
+}
+
+/// 节点选择器
+#[derive(Debug, Clone)]
+pub(super) enum Selector {
+    Name(String),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, i64),
+    Wildcard,
+    Filter(Expr),
+}
+
+/// 过滤表达式
+#[derive(Debug, Clone)]
+pub(super) enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Operand, CompareOp, Operand),
+    Exists(Operand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Operand {
+    /// 以@开头的相对路径
+    Current(Vec<Segment>),
+    /// 以$开头的绝对路径
+    Root(Vec<Segment>),
+    Literal(Value),
+}
@@ -0,0 +1,335 @@
+use crate::{error::ERR_PARSE, Result, Value, OK};
+
+use super::model::{CompareOp, Expr, JsonPath, Operand, Segment, Selector};
+
+/// JSONPath表达式解析器
+pub(super) struct Parser {
+    expr: String,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    pub(super) fn new(expr: &str) -> Self {
+        Parser {
+            expr: expr.to_string(),
+            chars: expr.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    pub(super) fn parse_path(mut self) -> Result<JsonPath> {
+        self.skip_whitespace();
+        self.expect('$')?;
+        let segments = self.parse_segments()?;
+        self.skip_whitespace();
+        if self.pos < self.chars.len() {
+            return Err(self.error("存在无法解析的内容"));
+        }
+        OK(JsonPath { segments })
+    }
+
+    fn parse_segments(&mut self) -> Result<Vec<Segment>> {
+        let mut segments = vec![];
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.pos += 1;
+                    if self.peek() == Some('.') {
+                        self.pos += 1;
+                        let selectors = if self.peek() == Some('[') {
+                            self.parse_bracket()?
+                        } else {
+                            vec![self.parse_dot_selector()?]
+                        };
+                        segments.push(Segment::Descendant(selectors));
+                    } else {
+                        segments.push(Segment::Child(vec![self.parse_dot_selector()?]));
+                    }
+                }
+                Some('[') => segments.push(Segment::Child(self.parse_bracket()?)),
+                _ => return OK(segments),
+            }
+        }
+    }
+
+    fn parse_dot_selector(&mut self) -> Result<Selector> {
+        if self.peek() == Some('*') {
+            self.pos += 1;
+            return OK(Selector::Wildcard);
+        }
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '_' || c == '-' || c == '$' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if start == self.pos {
+            return Err(self.error("缺少节点名称"));
+        }
+        OK(Selector::Name(self.chars[start..self.pos].iter().collect()))
+    }
+
+    fn parse_bracket(&mut self) -> Result<Vec<Selector>> {
+        self.expect('[')?;
+        let mut selectors = vec![];
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.parse_bracket_selector()?);
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return OK(selectors),
+                _ => return Err(self.error("缺少]")),
+            }
+        }
+    }
+
+    fn parse_bracket_selector(&mut self) -> Result<Selector> {
+        match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                OK(Selector::Wildcard)
+            }
+            Some('\'') | Some('"') => OK(Selector::Name(self.parse_string()?)),
+            Some('?') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                OK(Selector::Filter(self.parse_or()?))
+            }
+            _ => {
+                let start = self.parse_opt_int()?;
+                self.skip_whitespace();
+                if self.peek() != Some(':') {
+                    return match start {
+                        Some(v) => OK(Selector::Index(v)),
+                        None => Err(self.error("不合法的选择器")),
+                    };
+                }
+                self.pos += 1;
+                self.skip_whitespace();
+                let end = self.parse_opt_int()?;
+                self.skip_whitespace();
+                let mut step = 1;
+                if self.peek() == Some(':') {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    step = self.parse_opt_int()?.unwrap_or(1);
+                }
+                if step == 0 {
+                    return Err(self.error("切片步长不能为0"));
+                }
+                OK(Selector::Slice(start, end, step))
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut left = self.parse_and()?;
+        loop {
+            self.skip_whitespace();
+            if self.consume("||") {
+                let right = self.parse_and()?;
+                left = Expr::Or(Box::new(left), Box::new(right));
+            } else {
+                return OK(left);
+            }
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut left = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            if self.consume("&&") {
+                let right = self.parse_unary()?;
+                left = Expr::And(Box::new(left), Box::new(right));
+            } else {
+                return OK(left);
+            }
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        self.skip_whitespace();
+        if self.peek() == Some('!') && self.chars.get(self.pos + 1) != Some(&'=') {
+            self.pos += 1;
+            return OK(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let expr = self.parse_or()?;
+            self.skip_whitespace();
+            self.expect(')')?;
+            return OK(expr);
+        }
+        let left = self.parse_operand()?;
+        self.skip_whitespace();
+        let op = if self.consume("==") {
+            CompareOp::Eq
+        } else if self.consume("!=") {
+            CompareOp::Ne
+        } else if self.consume("<=") {
+            CompareOp::Le
+        } else if self.consume(">=") {
+            CompareOp::Ge
+        } else if self.consume("<") {
+            CompareOp::Lt
+        } else if self.consume(">") {
+            CompareOp::Gt
+        } else {
+            return match left {
+                Operand::Literal(_) => Err(self.error("过滤表达式中的常量需与路径进行比较")),
+                _ => OK(Expr::Exists(left)),
+            };
+        };
+        self.skip_whitespace();
+        let right = self.parse_operand()?;
+        OK(Expr::Compare(left, op, right))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand> {
+        match self.peek() {
+            Some('@') => {
+                self.pos += 1;
+                OK(Operand::Current(self.parse_segments()?))
+            }
+            Some('$') => {
+                self.pos += 1;
+                OK(Operand::Root(self.parse_segments()?))
+            }
+            Some('\'') | Some('"') => OK(Operand::Literal(Value::String(self.parse_string()?))),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(_) => {
+                if self.consume("true") {
+                    OK(Operand::Literal(Value::Bool(true)))
+                } else if self.consume("false") {
+                    OK(Operand::Literal(Value::Bool(false)))
+                } else if self.consume("null") {
+                    OK(Operand::Literal(Value::Null))
+                } else {
+                    Err(self.error("不合法的表达式"))
+                }
+            }
+            None => Err(self.error("表达式不完整")),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Operand> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || c == '+' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        if let Ok(v) = text.parse::<i64>() {
+            return OK(Operand::Literal(Value::I64(v)));
+        }
+        match text.parse::<f64>() {
+            Ok(v) => OK(Operand::Literal(Value::F64(v))),
+            Err(_) => Err(self.error(format!("数值[{}]不合法", text).as_str())),
+        }
+    }
+
+    fn parse_opt_int(&mut self) -> Result<Option<i64>> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if start == self.pos {
+            return OK(None);
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<i64>()
+            .map(Some)
+            .map_err(|_| self.error(format!("整数[{}]不合法", text).as_str()))
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        let quote = self.next().unwrap();
+        let mut res = String::new();
+        loop {
+            match self.next() {
+                Some('\\') => match self.next() {
+                    Some('n') => res.push('\n'),
+                    Some('t') => res.push('\t'),
+                    Some(c) => res.push(c),
+                    None => return Err(self.error("字符串未结束")),
+                },
+                Some(c) if c == quote => return OK(res),
+                Some(c) => res.push(c),
+                None => return Err(self.error("字符串未结束")),
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn consume(&mut self, s: &str) -> bool {
+        let len = s.chars().count();
+        if self.pos + len <= self.chars.len()
+            && self.chars[self.pos..self.pos + len]
+                .iter()
+                .copied()
+                .eq(s.chars())
+        {
+            self.pos += len;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.next() == Some(c) {
+            OK(())
+        } else {
+            Err(self.error(format!("缺少{}", c).as_str()))
+        }
+    }
+
+    fn error(&self, msg: &str) -> crate::error::AppError {
+        ERR_PARSE.msg_detail(
+            format!(
+                "JSONPath表达式[{}]在第{}个字符处解析失败：{}",
+                self.expr, self.pos, msg
+            )
+            .as_str(),
+        )
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        bean::{AsValueTrait, PointerMutTrait, PointerTrait},
+        date::DateTime,
+        query::{query, JsonPath},
+        Value,
+    };
+
+    fn orders() -> Value {
+        json!({
+            "orders": [
+                {"id": 1, "items": [{"sku": "a", "price": 5}, {"sku": "b", "price": 12.5}]},
+                {"id": 2, "items": [{"sku": "c", "price": 30}]}
+            ],
+            "limit": 10
+        })
+        .as_value()
+        .unwrap()
+    }
+
+    fn pointers(value: &Value, expr: &str) -> Vec<String> {
+        query(value, expr)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.pointer)
+            .collect()
+    }
+
+    #[test]
+    fn test_query() {
+        let value = orders();
+        let res = query(&value, "$.orders[*].items[?(@.price > 10)].sku").unwrap();
+        let skus: Vec<&str> = res.iter().map(|m| m.value.as_str().unwrap()).collect();
+        assert_eq!(skus, vec!["b", "c"]);
+        assert_eq!(res[0].pointer, "/orders/0/items/1/sku");
+        assert_eq!(
+            value.p(res[1].pointer.as_str()).unwrap().as_str().unwrap(),
+            "c"
+        );
+
+        assert_eq!(
+            pointers(&value, "$..sku"),
+            vec![
+                "/orders/0/items/0/sku",
+                "/orders/0/items/1/sku",
+                "/orders/1/items/0/sku"
+            ]
+        );
+        assert_eq!(pointers(&value, "$.orders[-1].id"), vec!["/orders/1/id"]);
+        assert_eq!(
+            pointers(&value, "$['orders'][0:2]"),
+            vec!["/orders/0", "/orders/1"]
+        );
+        assert_eq!(
+            pointers(&value, "$.orders[?(@.id == 2 || !@.items)].id"),
+            vec!["/orders/1/id"]
+        );
+        assert_eq!(
+            pointers(&value, "$.orders[*].items[?(@.price < $.limit)]"),
+            vec!["/orders/0/items/0"]
+        );
+        assert_eq!(
+            pointers(&value, "$.orders[1::9223372036854775807]"),
+            vec!["/orders/1"]
+        );
+        assert_eq!(
+            pointers(&value, "$.orders[::-9223372036854775808]"),
+            vec!["/orders/1"]
+        );
+        assert!(pointers(&value, "$.missing[*]").is_empty());
+    }
+
+    #[test]
+    fn test_query_date() {
+        let mut value = json!({"logs": [{"id": 1}, {"id": 2}]}).as_value().unwrap();
+        for (i, s) in ["2022-10-01 08:00:00", "2022-10-02 08:00:00"]
+            .iter()
+            .enumerate()
+        {
+            let date = Value::DateTime(DateTime::parse_str(s).unwrap());
+            value
+                .set_by_pointer(format!("/logs/{}/time", i).as_str(), date)
+                .unwrap();
+        }
+        let path = JsonPath::parse("$.logs[?(@.time >= '2022-10-02 00:00:00')].id").unwrap();
+        let res = path.query(&value);
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].value.as_i64().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_query_error() {
+        assert!(JsonPath::parse("orders").is_err());
+        assert!(JsonPath::parse("$.orders[").is_err());
+        assert!(JsonPath::parse("$.orders[::0]").is_err());
+        assert!(JsonPath::parse("$[?(@.a == )]").is_err());
+    }
+}
@@ -31,6 +31,15 @@ pub(super) fn get_handlebars() -> MutexGuard<'static, Handlebars<'static>> {
     global
 }
 
+/// 以不进行HTML转义的方式使用全局模板引擎，用于渲染纯文本，完成后恢复默认的转义规则
+pub(super) fn with_text_handlebars<T>(f: impl FnOnce(&Handlebars<'static>) -> T) -> T {
+    let mut global = get_handlebars();
+    global.register_escape_fn(handlebars::no_escape);
+    let res = f(&global);
+    global.unregister_escape_fn();
+    res
+}
+
 /// 模板引擎初始化
 fn init(global: &mut MutexGuard<Handlebars>) {
     global.register_helper("$", Box::new(place_helper));
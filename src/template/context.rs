@@ -12,7 +12,7 @@ pub enum ContextType {
     /// 值类型
     ValueType(Value),
     /// 调用类型
-    InvokerType(Box<dyn Fn(&mut HashMap<String, ContextType>) -> Value>),
+    InvokerType(Box<dyn Fn(&HashMap<String, ContextType>) -> Value>),
 }
 
 impl std::fmt::Debug for ContextType {
@@ -47,7 +47,7 @@ pub trait TemplateContextExt {
     fn insert_invoker(
         &mut self,
         key: &str,
-        invoker: Box<dyn Fn(&mut HashMap<String, ContextType>) -> Value>,
+        invoker: Box<dyn Fn(&HashMap<String, ContextType>) -> Value>,
     );
 }
 
@@ -77,7 +77,7 @@ impl TemplateContextExt for HashMap<String, ContextType> {
     fn insert_invoker(
         &mut self,
         key: &str,
-        invoker: Box<dyn Fn(&mut HashMap<String, ContextType>) -> Value>,
+        invoker: Box<dyn Fn(&HashMap<String, ContextType>) -> Value>,
     ) {
         self.insert(key.to_string(), ContextType::InvokerType(invoker));
     }
@@ -10,4 +10,5 @@ mod helper;
 pub use context::{ContextType, TemplateContextExt};
 pub use render::{
     render_simple_template, render_sql_template, render_template, render_template_recursion,
+    render_text_template,
 };
@@ -9,7 +9,7 @@ use crate::{
 };
 
 use super::{
-    base::{get_handlebars, PLACE_CONTEXT},
+    base::{get_handlebars, with_text_handlebars, PLACE_CONTEXT},
     context::{ContextType, TemplateContextExt},
 };
 
@@ -28,6 +28,15 @@ pub fn render_simple_template(template: String, value: &Value) -> Result<String>
     }
 }
 
+/// 根据纯文本渲染模板，变量不会进行HTML转义，适用于提示信息等非HTML文本
+pub fn render_text_template(template: &str, value: &Value) -> Result<String> {
+    let data = serde_json::Value::from_value(value)?;
+    match with_text_handlebars(|x| x.render_template(template, &data)) {
+        Ok(v) => OK(v),
+        Err(e) => Err(ERR_FORMAT.msg_detail("模板渲染失败").cause(e)),
+    }
+}
+
 /// 根据SQL文本渲染模板，返回的结果包括占位符及变量
 pub fn render_sql_template(template: String, param: &Value) -> Result<(String, Vec<Value>)> {
     render_template(template, param).map(|(a, b)| (a.compact(), Vec::from_iter(b.into_values())))
@@ -69,7 +78,6 @@ pub fn render_template_recursion(
     })
 }
 
-#[allow(clippy::cast_ref_to_mut)]
 fn render_template_recursion_inner(
     context: &HashMap<String, ContextType>,
     key: &str,
@@ -83,9 +91,6 @@ fn render_template_recursion_inner(
         },
         None => return Err(ERR_ARGUMENT.msg_detail(format!("模板定义{}不存在", &key).as_str())),
     };
-    let context_mut = unsafe {
-        &mut *(context as *const HashMap<String, ContextType> as *mut HashMap<String, ContextType>)
-    };
     let param = &mut BTreeMap::<String, Value>::new();
     if !root_attrs.is_empty() {
         for item_name in root_attrs {
@@ -104,7 +109,7 @@ fn render_template_recursion_inner(
                         param.insert_value(item_name, v.clone())?;
                     }
                     ContextType::InvokerType(it) => {
-                        param.insert_value(item_name, it.as_ref()(context_mut))?;
+                        param.insert_value(item_name, it.as_ref()(context))?;
                     }
                 },
                 None => {
@@ -115,7 +120,7 @@ fn render_template_recursion_inner(
             };
         }
     }
-    match context_mut.get_value("_root") {
+    match context.get_value("_root") {
         Ok(v) => match v {
             Some(v2) => param.insert("_root".to_string(), v2.clone()),
             None => None,
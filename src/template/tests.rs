@@ -5,12 +5,15 @@ mod tests {
     use serde_json::json;
 
     use crate::{
-        bean::AsValueTrait,
         context::ContextTrait,
         template::{
             context::TemplateContextExt,
-            render::{render_sql_template, render_template, render_template_recursion},
+            render::{
+                render_simple_template, render_sql_template, render_template,
+                render_template_recursion, render_text_template,
+            },
         },
+        value,
     };
 
     #[test]
@@ -53,7 +56,7 @@ mod tests {
                 select * from table where name={{$ this}} 
             "#
             .to_string(),
-            &json!(["张三"]).as_value().unwrap(),
+            &value!(["张三"]),
         )
         .unwrap();
         assert!(res.0.contains("$1"));
@@ -70,7 +73,7 @@ mod tests {
                 {{/each}}
             "#
             .to_string(),
-            &json!(["张三", "李四"]).as_value().unwrap(),
+            &value!(["张三", "李四"]),
         )
         .unwrap();
         assert!(res.0.contains("$1"));
@@ -87,7 +90,7 @@ mod tests {
                 {{/each}}
             "#
             .to_string(),
-            &json!({
+            &value!({
                 "name": "zhangshan",
                 "age": [13, 14],
                 "address": [{
@@ -97,9 +100,7 @@ mod tests {
                     "country": "usa",
                     "city": "newyork",
                 }]
-            })
-            .as_value()
-            .unwrap(),
+            }),
         )
         .unwrap();
         assert!(res.0.contains("$1"));
@@ -116,7 +117,7 @@ mod tests {
                 {{/each}} and name={{$ name}}
             "#
             .to_string(),
-            &json!({
+            &value!({
                 "name": "zhangshan",
                 "age": [13, 14],
                 "address": [{
@@ -126,11 +127,18 @@ mod tests {
                     "country": "usa",
                     "city": "newyork",
                 }]
-            })
-            .as_value()
-            .unwrap(),
+            }),
         )
         .unwrap();
         assert!(res.0.contains("$1"));
     }
+
+    #[test]
+    fn test_render_text_template() {
+        let param = value!({"name": "a&b"});
+        let text = render_text_template("<{{$ name \"n\"}}> {{name}}", &param).unwrap();
+        assert_eq!(text, "<n> a&b");
+        let html = render_simple_template("{{name}}".to_string(), &param).unwrap();
+        assert_eq!(html, "a&amp;b");
+    }
 }
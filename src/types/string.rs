@@ -28,6 +28,34 @@ pub trait StringExt {
 
     /// 检查是否包含指定字符串，且忽略其大小写
     fn contains_ignore_case(&self, pat: String) -> bool;
+
+    /// 转换为下划线格式，如：userName、UserName、user-name均转换为user_name，首尾的下划线保持不变
+    fn to_snake_case(&self) -> String
+    where
+        Self: AsRef<str>,
+    {
+        convert_case(self.as_ref(), |words| words.join("_"))
+    }
+
+    /// 转换为小驼峰格式，如：user_name、UserName、user-name均转换为userName，首尾的下划线保持不变
+    fn to_camel_case(&self) -> String
+    where
+        Self: AsRef<str>,
+    {
+        convert_case(self.as_ref(), |words| {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    let mut chars = w.chars();
+                    match chars.next() {
+                        Some(c) if i > 0 => c.to_uppercase().chain(chars).collect(),
+                        _ => w.to_string(),
+                    }
+                })
+                .collect()
+        })
+    }
 }
 
 impl StringExt for &str {
@@ -123,6 +151,44 @@ impl StringExt for String {
     }
 }
 
+/// 拆分单词后按指定方式连接，首尾的下划线不参与拆分，如：_id保持为_id
+fn convert_case(s: &str, join: impl Fn(&[String]) -> String) -> String {
+    let body = s.trim_matches('_');
+    if body.is_empty() {
+        return s.to_string();
+    }
+    let start = s.len() - s.trim_start_matches('_').len();
+    let end = start + body.len();
+    format!("{}{}{}", &s[..start], join(&words(body)), &s[end..])
+}
+
+/// 按下划线、中划线、空格及大小写边界拆分为小写的单词，连续的大写字母视为一个单词，如：HTTPServer拆分为http、server
+fn words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut res = vec![];
+    let mut word = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '_' || *c == '-' || c.is_whitespace() {
+            if !word.is_empty() {
+                res.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|x| x.is_lowercase());
+            if !prev.is_uppercase() || next_lower {
+                res.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        res.push(word);
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::StringExt;
@@ -134,5 +200,13 @@ mod tests {
         assert_eq!("on".to_string().str_to_bool().unwrap(), true);
         assert_eq!("yes".str_to_bool().unwrap(), true);
         assert_eq!("no".to_string().str_to_bool().unwrap(), false);
+        assert_eq!("userName".to_snake_case(), "user_name");
+        assert_eq!("HTTPServer-port".to_snake_case(), "http_server_port");
+        assert_eq!("user_id2".to_camel_case(), "userId2");
+        assert_eq!("UserName".to_string().to_camel_case(), "userName");
+        assert_eq!("_id".to_snake_case(), "_id");
+        assert_eq!("_id".to_camel_case(), "_id");
+        assert_eq!("__user_name_".to_camel_case(), "__userName_");
+        assert_eq!("_UserId".to_string().to_snake_case(), "_user_id");
     }
 }
@@ -0,0 +1,21 @@
+//! 数据校验工具类
+//!
+//! 支持以JSON Schema(draft 2020-12)的子集或Rust构建器定义校验规则，并对内置Value对象进行校验，
+//! 校验时会收集所有不满足规则的节点，以一个ERR_VALIDATION异常返回，
+//! 异常上下文中的violations为包含pointer、keyword、message的列表，pointer为对应节点的RFC6901路径
+//!
+//! 支持的关键字：
+//! - `type`：null、boolean、integer、number、string、array、object，可使用数组指定多个类型
+//! - `enum`、`required`、`properties`、`additionalProperties`、`items`
+//! - `minimum`、`maximum`、`exclusiveMinimum`、`exclusiveMaximum`
+//! - `minLength`、`maxLength`、`pattern`、`minItems`、`maxItems`
+//! - `format`：date、date-time、time，其余格式不做校验
+//!
+//! 内置日期类型均视为string，按其字符串格式参与校验
+mod model;
+mod parser;
+mod tests;
+mod validator;
+
+pub use model::{Schema, SchemaType, Violation};
+pub use validator::validate;
@@ -0,0 +1,337 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use regex::Regex;
+
+use crate::{
+    bean::AsValueTrait,
+    error::{AppError, ERR_PARSE},
+    value, Result, Value, OK,
+};
+
+use super::validator::is_number;
+
+/// 数据类型，名称同JSON Schema中的定义
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaType {
+    Null,
+    Boolean,
+    /// 整数及值为整数的浮点数、Decimal
+    Integer,
+    /// 所有数值，包含integer
+    Number,
+    /// 字符串及内置日期类型
+    String,
+    Array,
+    Object,
+}
+
+impl SchemaType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SchemaType::Null => "null",
+            SchemaType::Boolean => "boolean",
+            SchemaType::Integer => "integer",
+            SchemaType::Number => "number",
+            SchemaType::String => "string",
+            SchemaType::Array => "array",
+            SchemaType::Object => "object",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<SchemaType> {
+        match name {
+            "null" => Some(SchemaType::Null),
+            "boolean" => Some(SchemaType::Boolean),
+            "integer" => Some(SchemaType::Integer),
+            "number" => Some(SchemaType::Number),
+            "string" => Some(SchemaType::String),
+            "array" => Some(SchemaType::Array),
+            "object" => Some(SchemaType::Object),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 校验规则，可通过Schema::from_value从JSON Schema解析，或使用构建方法定义
+///
+/// 构建过程中的异常(如pattern不是合法的正则表达式)会在校验时以ERR_PARSE异常返回
+///
+/// ```ignore
+/// let schema = Schema::object()
+///     .property("name", Schema::string().min_length(1))
+///     .property("age", Schema::integer().minimum(0).maximum(150))
+///     .property("tags", Schema::array(Schema::string()).max_items(10))
+///     .required(&["name", "age"])
+///     .deny_additional_properties();
+/// schema.validate(&value)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// 为true时不允许任何数据，对应JSON Schema中的false
+    pub(super) reject: bool,
+    pub(super) types: Vec<SchemaType>,
+    pub(super) enum_values: Option<Vec<Value>>,
+    pub(super) minimum: Option<Value>,
+    pub(super) maximum: Option<Value>,
+    pub(super) exclusive_minimum: Option<Value>,
+    pub(super) exclusive_maximum: Option<Value>,
+    pub(super) min_length: Option<usize>,
+    pub(super) max_length: Option<usize>,
+    pub(super) pattern: Option<Regex>,
+    pub(super) format: Option<String>,
+    pub(super) items: Option<Box<Schema>>,
+    pub(super) min_items: Option<usize>,
+    pub(super) max_items: Option<usize>,
+    pub(super) properties: BTreeMap<String, Schema>,
+    pub(super) required: Vec<String>,
+    pub(super) additional_properties: Option<Box<Schema>>,
+    pub(super) error: Option<AppError>,
+}
+
+impl Schema {
+    /// 允许任何数据的规则，对应JSON Schema中的true或{}
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 不允许任何数据的规则，对应JSON Schema中的false
+    pub fn never() -> Self {
+        Schema {
+            reject: true,
+            ..Self::default()
+        }
+    }
+
+    /// 指定数据类型的规则
+    pub fn of(ty: SchemaType) -> Self {
+        Schema {
+            types: vec![ty],
+            ..Self::default()
+        }
+    }
+
+    pub fn null() -> Self {
+        Self::of(SchemaType::Null)
+    }
+
+    pub fn boolean() -> Self {
+        Self::of(SchemaType::Boolean)
+    }
+
+    pub fn integer() -> Self {
+        Self::of(SchemaType::Integer)
+    }
+
+    pub fn number() -> Self {
+        Self::of(SchemaType::Number)
+    }
+
+    pub fn string() -> Self {
+        Self::of(SchemaType::String)
+    }
+
+    /// 数组类型的规则，所有元素需满足items规则
+    pub fn array(items: Schema) -> Self {
+        Self::of(SchemaType::Array).items(items)
+    }
+
+    pub fn object() -> Self {
+        Self::of(SchemaType::Object)
+    }
+
+    /// 追加允许的数据类型
+    pub fn or_type(mut self, ty: SchemaType) -> Self {
+        if !self.types.contains(&ty) {
+            self.types.push(ty);
+        }
+        self
+    }
+
+    /// 允许数据为null
+    pub fn nullable(self) -> Self {
+        self.or_type(SchemaType::Null)
+    }
+
+    /// 数据需等于其中一项
+    pub fn enum_values(mut self, values: Vec<Value>) -> Self {
+        self.enum_values = Some(values);
+        self
+    }
+
+    /// 数值需大于等于指定值
+    pub fn minimum<T: AsValueTrait>(mut self, value: T) -> Self {
+        self.minimum = self.number_value("minimum", &value);
+        self
+    }
+
+    /// 数值需小于等于指定值
+    pub fn maximum<T: AsValueTrait>(mut self, value: T) -> Self {
+        self.maximum = self.number_value("maximum", &value);
+        self
+    }
+
+    /// 数值需大于指定值
+    pub fn exclusive_minimum<T: AsValueTrait>(mut self, value: T) -> Self {
+        self.exclusive_minimum = self.number_value("exclusiveMinimum", &value);
+        self
+    }
+
+    /// 数值需小于指定值
+    pub fn exclusive_maximum<T: AsValueTrait>(mut self, value: T) -> Self {
+        self.exclusive_maximum = self.number_value("exclusiveMaximum", &value);
+        self
+    }
+
+    /// 字符串的最小长度，按字符计算
+    pub fn min_length(mut self, len: usize) -> Self {
+        self.min_length = Some(len);
+        self
+    }
+
+    /// 字符串的最大长度，按字符计算
+    pub fn max_length(mut self, len: usize) -> Self {
+        self.max_length = Some(len);
+        self
+    }
+
+    /// 字符串需匹配的正则表达式，未指定^及$时仅需部分匹配
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        match Regex::new(pattern) {
+            Ok(v) => self.pattern = Some(v),
+            Err(e) => {
+                self.fail(ERR_PARSE.msg_detail(
+                    format!("pattern[{}]不是合法的正则表达式：{}", pattern, e).as_str(),
+                ))
+            }
+        }
+        self
+    }
+
+    /// 字符串格式，支持date、date-time、time
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// 数组元素需满足的规则
+    pub fn items(mut self, items: Schema) -> Self {
+        self.items = Some(Box::new(items));
+        self
+    }
+
+    /// 数组的最小长度
+    pub fn min_items(mut self, len: usize) -> Self {
+        self.min_items = Some(len);
+        self
+    }
+
+    /// 数组的最大长度
+    pub fn max_items(mut self, len: usize) -> Self {
+        self.max_items = Some(len);
+        self
+    }
+
+    /// Object中指定字段需满足的规则，字段不存在时不做校验
+    pub fn property(mut self, name: &str, schema: Schema) -> Self {
+        self.properties.insert(name.to_string(), schema);
+        self
+    }
+
+    /// Object中必须存在的字段
+    pub fn required(mut self, names: &[&str]) -> Self {
+        for name in names {
+            if !self.required.iter().any(|x| x == name) {
+                self.required.push(name.to_string());
+            }
+        }
+        self
+    }
+
+    /// Object中未在property中定义的字段需满足的规则
+    pub fn additional_properties(mut self, schema: Schema) -> Self {
+        self.additional_properties = Some(Box::new(schema));
+        self
+    }
+
+    /// Object中不允许存在未在property中定义的字段
+    pub fn deny_additional_properties(self) -> Self {
+        self.additional_properties(Schema::never())
+    }
+
+    fn number_value<T: AsValueTrait>(&mut self, keyword: &str, value: &T) -> Option<Value> {
+        match value.as_value() {
+            Ok(v) if is_number(&v) => Some(v),
+            Ok(v) => {
+                self.fail(ERR_PARSE.msg_detail(format!("{}[{:?}]必须为数值", keyword, v).as_str()));
+                None
+            }
+            Err(e) => {
+                self.fail(e);
+                None
+            }
+        }
+    }
+
+    fn fail(&mut self, e: AppError) {
+        if self.error.is_none() {
+            self.error = Some(e);
+        }
+    }
+
+    /// 检查规则在构建过程中是否出现异常，包含所有下级规则
+    pub(super) fn check(&self) -> Result<()> {
+        if let Some(e) = &self.error {
+            return Err(e.clone());
+        }
+        let children = self
+            .items
+            .iter()
+            .chain(self.additional_properties.iter())
+            .map(|x| x.as_ref())
+            .chain(self.properties.values());
+        for schema in children {
+            schema.check()?;
+        }
+        OK(())
+    }
+}
+
+/// 一项校验失败信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// 校验失败节点的RFC6901路径，根节点为空字符串
+    pub pointer: String,
+    /// 校验失败的关键字，如：required、type
+    pub keyword: String,
+    pub message: String,
+}
+
+impl Violation {
+    pub(super) fn new(pointer: &str, keyword: &str, message: String) -> Self {
+        Violation {
+            pointer: pointer.to_string(),
+            keyword: keyword.to_string(),
+            message,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        value!({
+            "pointer": self.pointer,
+            "keyword": self.keyword,
+            "message": self.message,
+        })
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]{}", self.pointer, self.message)
+    }
+}
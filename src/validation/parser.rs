@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use crate::{bean::escape_token, error::ERR_PARSE, Result, Value, OK};
+
+use super::{
+    model::{Schema, SchemaType},
+    validator::is_number,
+};
+
+/// 不影响校验结果的关键字
+const ANNOTATIONS: [&str; 9] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "readOnly",
+    "writeOnly",
+];
+
+impl Schema {
+    /// 从JSON Schema解析校验规则
+    ///
+    /// 不支持的关键字及关键字取值不合法时返回ERR_PARSE异常，异常信息中包含对应的规则路径
+    pub fn from_value(value: &Value) -> Result<Schema> {
+        parse(value, "")
+    }
+}
+
+fn parse(value: &Value, pointer: &str) -> Result<Schema> {
+    let obj = match value {
+        Value::Bool(true) => return OK(Schema::new()),
+        Value::Bool(false) => return OK(Schema::never()),
+        Value::Object(obj) => obj,
+        _ => return Err(error(pointer, "规则必须为Object或布尔值")),
+    };
+    let mut schema = Schema::new();
+    for (k, v) in obj {
+        let child = format!("{}/{}", pointer, escape_token(k));
+        let child = child.as_str();
+        match k.as_str() {
+            "type" => schema = parse_types(schema, v, child)?,
+            "enum" => {
+                schema = schema.enum_values(
+                    v.as_array()
+                        .map_err(|_| error(child, "必须为数组"))?
+                        .clone(),
+                )
+            }
+            "minimum" => schema.minimum = Some(number(v, child)?),
+            "maximum" => schema.maximum = Some(number(v, child)?),
+            "exclusiveMinimum" => schema.exclusive_minimum = Some(number(v, child)?),
+            "exclusiveMaximum" => schema.exclusive_maximum = Some(number(v, child)?),
+            "minLength" => schema.min_length = Some(length(v, child)?),
+            "maxLength" => schema.max_length = Some(length(v, child)?),
+            "minItems" => schema.min_items = Some(length(v, child)?),
+            "maxItems" => schema.max_items = Some(length(v, child)?),
+            "pattern" => {
+                schema = schema.pattern(v.as_str().map_err(|_| error(child, "必须为字符串"))?);
+                if let Some(e) = schema.error.take() {
+                    return Err(e);
+                }
+            }
+            "format" => {
+                schema = schema.format(v.as_str().map_err(|_| error(child, "必须为字符串"))?)
+            }
+            "items" => schema = schema.items(parse(v, child)?),
+            "properties" => schema.properties = parse_properties(v, child)?,
+            "required" => {
+                let names = v
+                    .as_array()
+                    .ok()
+                    .and_then(|arr| {
+                        arr.iter()
+                            .map(|x| x.as_str().ok())
+                            .collect::<Option<Vec<&str>>>()
+                    })
+                    .ok_or_else(|| error(child, "必须为字符串数组"))?;
+                schema = schema.required(&names);
+            }
+            "additionalProperties" => schema = schema.additional_properties(parse(v, child)?),
+            k if ANNOTATIONS.contains(&k) => {}
+            _ => return Err(error(child, "不支持的关键字")),
+        }
+    }
+    OK(schema)
+}
+
+fn parse_types(mut schema: Schema, value: &Value, pointer: &str) -> Result<Schema> {
+    let names = match value {
+        Value::String(v) => vec![v.as_str()],
+        Value::Array(arr) => arr
+            .iter()
+            .map(|x| x.as_str().ok())
+            .collect::<Option<Vec<&str>>>()
+            .ok_or_else(|| error(pointer, "必须为字符串或字符串数组"))?,
+        _ => return Err(error(pointer, "必须为字符串或字符串数组")),
+    };
+    for name in names {
+        let ty = SchemaType::from_name(name)
+            .ok_or_else(|| error(pointer, format!("不支持的类型{}", name).as_str()))?;
+        schema = schema.or_type(ty);
+    }
+    OK(schema)
+}
+
+fn parse_properties(value: &Value, pointer: &str) -> Result<BTreeMap<String, Schema>> {
+    let obj = value
+        .as_object()
+        .map_err(|_| error(pointer, "必须为Object"))?;
+    let mut res = BTreeMap::new();
+    for (k, v) in obj {
+        let child = format!("{}/{}", pointer, escape_token(k));
+        res.insert(k.clone(), parse(v, child.as_str())?);
+    }
+    OK(res)
+}
+
+fn number(value: &Value, pointer: &str) -> Result<Value> {
+    if is_number(value) {
+        OK(value.clone())
+    } else {
+        Err(error(pointer, "必须为数值"))
+    }
+}
+
+fn length(value: &Value, pointer: &str) -> Result<usize> {
+    value
+        .as_u64()
+        .ok()
+        .and_then(|x| usize::try_from(x).ok())
+        .ok_or_else(|| error(pointer, "必须为非负整数"))
+}
+
+fn error(pointer: &str, msg: &str) -> crate::error::AppError {
+    ERR_PARSE.msg_detail(format!("JSON Schema[{}]{}", pointer, msg).as_str())
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use crate::{
+        date::Date,
+        validation::{validate, Schema, SchemaType},
+        value, Value,
+    };
+
+    fn order_schema() -> Schema {
+        Schema::from_value(&value!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["id", "name", "created"],
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "name": {"type": "string", "minLength": 1, "maxLength": 8, "pattern": "^[a-z]+$"},
+                "price": {"type": "number", "exclusiveMinimum": 0, "maximum": 100},
+                "status": {"enum": ["new", "paid"]},
+                "created": {"type": "string", "format": "date-time"},
+                "day": {"type": ["string", "null"], "format": "date"},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 2},
+            },
+            "additionalProperties": false,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let schema = order_schema();
+        let value = value!({
+            "id": 1,
+            "name": "apple",
+            "price": Decimal::from_str("99.99").unwrap(),
+            "status": "paid",
+            "created": "2022-10-01T12:30:00+08:00",
+            "day": Date::parse_str("2022-10-01").unwrap(),
+            "tags": ["a", "b"],
+        });
+        assert!(schema.validate(&value).is_ok());
+        assert!(validate(
+            &value!({"id": 2.0, "name": "a", "created": "2022-10-01 12:30:00", "day": null}),
+            &schema
+        )
+        .is_ok());
+
+        let value = value!({
+            "id": 0,
+            "name": "Apple!",
+            "price": 0,
+            "status": "closed",
+            "created": "2022-10-01",
+            "day": "2022-13-01",
+            "tags": ["a", 1, "c"],
+            "extra": true,
+        });
+        let pointers: Vec<(String, String)> = schema
+            .violations(&value)
+            .unwrap()
+            .into_iter()
+            .map(|x| (x.pointer, x.keyword))
+            .collect();
+        let expected = vec![
+            ("/created", "format"),
+            ("/day", "format"),
+            ("/extra", "additionalProperties"),
+            ("/id", "minimum"),
+            ("/name", "pattern"),
+            ("/price", "exclusiveMinimum"),
+            ("/status", "enum"),
+            ("/tags", "maxItems"),
+            ("/tags/1", "type"),
+        ];
+        assert_eq!(
+            pointers,
+            expected
+                .into_iter()
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect::<Vec<(String, String)>>()
+        );
+
+        let err = schema.validate(&value!({"name": 1})).unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_VALIDATION");
+        let violations = err.context_map_ref().unwrap().get("violations").unwrap();
+        let violations = violations.as_array().unwrap();
+        assert_eq!(violations.len(), 3);
+        assert_eq!(
+            violations[0],
+            value!({"pointer": "/id", "keyword": "required", "message": "缺少必填字段"})
+        );
+        assert_eq!(
+            violations[2].as_object().unwrap().get("pointer"),
+            Some(&Value::String("/name".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_schema_builder() {
+        let schema = Schema::object()
+            .property("name", Schema::string().min_length(1))
+            .property("age", Schema::integer().minimum(0).maximum(150).nullable())
+            .property("a/b", Schema::array(Schema::number()).min_items(1))
+            .additional_properties(Schema::of(SchemaType::Boolean))
+            .required(&["name", "age"]);
+        assert!(schema
+            .validate(&value!({"name": "张三", "age": null, "a/b": [1.5], "vip": true}))
+            .is_ok());
+        let violations = schema
+            .violations(&value!({"name": "", "age": 200, "a/b": [], "vip": 1}))
+            .unwrap();
+        let pointers: Vec<&str> = violations.iter().map(|x| x.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/a~1b", "/age", "/name", "/vip"]);
+        assert!(schema.validate(&value!([])).is_err());
+
+        let err = Schema::string()
+            .pattern("(")
+            .validate(&value!("a"))
+            .unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_PARSE");
+        let err = Schema::number()
+            .minimum("1")
+            .validate(&value!(1))
+            .unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_PARSE");
+        let err = Schema::from_value(&value!({"properties": {"a": {"minimum": "x"}}})).unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_PARSE");
+        assert!(Schema::from_value(&value!({"oneOf": []})).is_err());
+        assert!(Schema::from_value(&value!({"type": "date"})).is_err());
+
+        let violations = Schema::number().violations(&Value::F64(f64::NAN)).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].keyword, "finite");
+        assert!(violations[0].message.contains("NaN"));
+    }
+}
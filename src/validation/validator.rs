@@ -0,0 +1,317 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use crate::{
+    bean::escape_token,
+    date::{Date, DateTime, Time},
+    error::ERR_VALIDATION,
+    value_type::value_cmp,
+    Result, Value, OK,
+};
+
+use super::model::{Schema, SchemaType, Violation};
+
+/// 按规则校验Value对象，同Schema::validate
+pub fn validate(value: &Value, schema: &Schema) -> Result<()> {
+    schema.validate(value)
+}
+
+impl Schema {
+    /// 校验Value对象，存在不满足规则的节点时返回ERR_VALIDATION异常
+    ///
+    /// 异常上下文中的violations为所有校验失败信息的列表，规则构建过程中出现异常时直接返回该异常
+    pub fn validate(&self, value: &Value) -> Result<()> {
+        let violations = self.violations(value)?;
+        if violations.is_empty() {
+            return OK(());
+        }
+        let detail = violations
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join("；");
+        Err(ERR_VALIDATION
+            .msg_detail(format!("共{}项参数校验失败：{}", violations.len(), detail).as_str())
+            .context_value(
+                "violations".to_string(),
+                Value::Array(violations.iter().map(|x| x.to_value()).collect()),
+            ))
+    }
+
+    /// 校验Value对象，返回所有校验失败信息
+    pub fn violations(&self, value: &Value) -> Result<Vec<Violation>> {
+        self.check()?;
+        let mut res = vec![];
+        self.collect(value, "", &mut res);
+        OK(res)
+    }
+
+    fn collect(&self, value: &Value, pointer: &str, res: &mut Vec<Violation>) {
+        if self.reject {
+            res.push(Violation::new(
+                pointer,
+                "false",
+                "不允许存在该数据".to_string(),
+            ));
+            return;
+        }
+        if !self.types.is_empty() && !self.types.iter().any(|x| is_type(value, *x)) {
+            if let Some(v) = non_finite(value) {
+                res.push(Violation::new(
+                    pointer,
+                    "finite",
+                    format!("数值不能为NaN或无穷大，实际为{}", v),
+                ));
+            } else {
+                let expected = self
+                    .types
+                    .iter()
+                    .map(|x| x.name())
+                    .collect::<Vec<&str>>()
+                    .join("|");
+                res.push(Violation::new(
+                    pointer,
+                    "type",
+                    format!("类型应为{}，实际为{}", expected, type_name(value)),
+                ));
+            }
+        }
+        if let Some(values) = &self.enum_values {
+            if !values.iter().any(|x| enum_eq(value, x)) {
+                res.push(Violation::new(
+                    pointer,
+                    "enum",
+                    format!("应为{:?}中的一项", values),
+                ));
+            }
+        }
+        if is_number(value) {
+            self.collect_number(value, pointer, res);
+        }
+        if let Some(text) = text(value) {
+            self.collect_string(value, text.as_str(), pointer, res);
+        }
+        match value {
+            Value::Array(arr) => self.collect_array(arr, pointer, res),
+            Value::Object(obj) => self.collect_object(obj, pointer, res),
+            _ => {}
+        }
+    }
+
+    fn collect_number(&self, value: &Value, pointer: &str, res: &mut Vec<Violation>) {
+        let bounds = [
+            (&self.minimum, "minimum", "大于等于", Ordering::Less, false),
+            (
+                &self.maximum,
+                "maximum",
+                "小于等于",
+                Ordering::Greater,
+                false,
+            ),
+            (
+                &self.exclusive_minimum,
+                "exclusiveMinimum",
+                "大于",
+                Ordering::Less,
+                true,
+            ),
+            (
+                &self.exclusive_maximum,
+                "exclusiveMaximum",
+                "小于",
+                Ordering::Greater,
+                true,
+            ),
+        ];
+        for (bound, keyword, name, fail, exclusive) in bounds {
+            if let Some(bound) = bound {
+                let ord = value_cmp(value, bound);
+                if ord == Some(fail) || (exclusive && ord == Some(Ordering::Equal)) {
+                    res.push(Violation::new(
+                        pointer,
+                        keyword,
+                        format!("数值应{}{}", name, bound),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn collect_string(&self, value: &Value, text: &str, pointer: &str, res: &mut Vec<Violation>) {
+        let len = text.chars().count();
+        if let Some(min) = self.min_length {
+            if len < min {
+                res.push(Violation::new(
+                    pointer,
+                    "minLength",
+                    format!("长度应大于等于{}", min),
+                ));
+            }
+        }
+        if let Some(max) = self.max_length {
+            if len > max {
+                res.push(Violation::new(
+                    pointer,
+                    "maxLength",
+                    format!("长度应小于等于{}", max),
+                ));
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(text) {
+                res.push(Violation::new(
+                    pointer,
+                    "pattern",
+                    format!("应匹配正则表达式{}", pattern),
+                ));
+            }
+        }
+        if let Some(format) = &self.format {
+            let valid = match (format.as_str(), value) {
+                ("date", Value::Date(_)) | ("date-time", Value::DateTime(_)) => true,
+                ("time", Value::Time(_)) => true,
+                ("date", Value::String(_)) => Date::parse_str(text).is_ok(),
+                ("date-time", Value::String(_)) => {
+                    chrono::DateTime::parse_from_rfc3339(text).is_ok()
+                        || DateTime::parse_str(text.replacen('T', " ", 1).as_str()).is_ok()
+                }
+                ("time", Value::String(_)) => Time::parse_str(text).is_ok(),
+                ("date", _) | ("date-time", _) | ("time", _) => false,
+                _ => true,
+            };
+            if !valid {
+                res.push(Violation::new(
+                    pointer,
+                    "format",
+                    format!("格式应为{}", format),
+                ));
+            }
+        }
+    }
+
+    fn collect_array(&self, arr: &[Value], pointer: &str, res: &mut Vec<Violation>) {
+        if let Some(min) = self.min_items {
+            if arr.len() < min {
+                res.push(Violation::new(
+                    pointer,
+                    "minItems",
+                    format!("元素个数应大于等于{}", min),
+                ));
+            }
+        }
+        if let Some(max) = self.max_items {
+            if arr.len() > max {
+                res.push(Violation::new(
+                    pointer,
+                    "maxItems",
+                    format!("元素个数应小于等于{}", max),
+                ));
+            }
+        }
+        if let Some(items) = &self.items {
+            for (i, v) in arr.iter().enumerate() {
+                items.collect(v, format!("{}/{}", pointer, i).as_str(), res);
+            }
+        }
+    }
+
+    fn collect_object(
+        &self,
+        obj: &BTreeMap<String, Value>,
+        pointer: &str,
+        res: &mut Vec<Violation>,
+    ) {
+        for name in &self.required {
+            if !obj.contains_key(name) {
+                res.push(Violation::new(
+                    format!("{}/{}", pointer, escape_token(name)).as_str(),
+                    "required",
+                    "缺少必填字段".to_string(),
+                ));
+            }
+        }
+        for (k, v) in obj {
+            let child = format!("{}/{}", pointer, escape_token(k));
+            match (self.properties.get(k), &self.additional_properties) {
+                (Some(schema), _) => schema.collect(v, child.as_str(), res),
+                (None, Some(schema)) if schema.reject => res.push(Violation::new(
+                    child.as_str(),
+                    "additionalProperties",
+                    "不允许存在该字段".to_string(),
+                )),
+                (None, Some(schema)) => schema.collect(v, child.as_str(), res),
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+pub(super) fn is_number(value: &Value) -> bool {
+    match value {
+        Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_) | Value::Decimal(_) => true,
+        Value::F32(v) => v.is_finite(),
+        Value::F64(v) => v.is_finite(),
+        _ => false,
+    }
+}
+
+/// NaN及无穷大的浮点数
+fn non_finite(value: &Value) -> Option<f64> {
+    match value {
+        Value::F32(v) if !v.is_finite() => Some(*v as f64),
+        Value::F64(v) if !v.is_finite() => Some(*v),
+        _ => None,
+    }
+}
+
+fn is_type(value: &Value, ty: SchemaType) -> bool {
+    match ty {
+        SchemaType::Null => matches!(value, Value::Null),
+        SchemaType::Boolean => matches!(value, Value::Bool(_)),
+        SchemaType::Integer => {
+            is_number(value) && value.as_decimal().is_ok_and(|x| x.fract().is_zero())
+        }
+        SchemaType::Number => is_number(value),
+        SchemaType::String => text(value).is_some(),
+        SchemaType::Array => matches!(value, Value::Array(_)),
+        SchemaType::Object => matches!(value, Value::Object(_)),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Binary(_) => "binary",
+        _ => [
+            SchemaType::Null,
+            SchemaType::Boolean,
+            SchemaType::Integer,
+            SchemaType::Number,
+            SchemaType::String,
+            SchemaType::Array,
+            SchemaType::Object,
+        ]
+        .into_iter()
+        .find(|x| is_type(value, *x))
+        .map_or("unknown", |x| x.name()),
+    }
+}
+
+/// 字符串及内置日期类型的字符串格式
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(v) => Some(v.clone()),
+        Value::Date(v) => Some(v.to_string()),
+        Value::Time(v) => Some(v.to_string()),
+        Value::DateTime(v) => Some(v.to_string()),
+        Value::YearMonth(v) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+/// 内置日期类型与字符串按字符串格式比较，其余同Value的相等规则
+fn enum_eq(value: &Value, expected: &Value) -> bool {
+    match (value, expected) {
+        (Value::String(_), _) => value == expected,
+        (_, Value::String(s)) => text(value).is_some_and(|x| &x == s),
+        _ => value == expected,
+    }
+}
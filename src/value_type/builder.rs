@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
+use crate::{
+    bean::{key_to_pointer, AsValueTrait, PointerMutTrait},
+    date::{Date, DateTime, Time, YearMonth},
+    error::AppError,
+    Result, OK,
+};
+
+use super::main::Value;
+
+/// 内置Value对象构建工具
+///
+/// 路径可使用server.port或/server/port格式，路径中不存在的节点会自动创建，
+/// 下一层级为数字或`-`时创建Array，否则创建Object。
+/// 构建过程中的异常会在build时返回，出现异常后的设置操作均被忽略
+///
+/// ```ignore
+/// let value = ValueBuilder::object()
+///     .string("server.host", "127.0.0.1")
+///     .i32("server.port", 8080)
+///     .date("release", Date::parse_str("2022-10-01")?)
+///     .string("/tags/-", "web")
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValueBuilder {
+    value: Value,
+    error: Option<AppError>,
+}
+
+impl Default for ValueBuilder {
+    fn default() -> Self {
+        Self::object()
+    }
+}
+
+impl ValueBuilder {
+    /// 以空Object为根节点
+    pub fn object() -> Self {
+        Self::from_value(Value::Object(BTreeMap::new()))
+    }
+
+    /// 以空Array为根节点
+    pub fn array() -> Self {
+        Self::from_value(Value::Array(vec![]))
+    }
+
+    /// 以已有的Value对象为根节点
+    pub fn from_value(value: Value) -> Self {
+        ValueBuilder { value, error: None }
+    }
+
+    /// 在指定路径设置Value对象
+    pub fn value(mut self, path: &str, value: Value) -> Self {
+        if self.error.is_none() {
+            let pointer = key_to_pointer(path);
+            if let Err(e) = self.value.set_by_pointer(pointer.as_str(), value) {
+                self.error = Some(e);
+            }
+        }
+        self
+    }
+
+    /// 在指定路径设置任意实现了AsValueTrait的对象
+    pub fn set<T: AsValueTrait + ?Sized>(self, path: &str, value: &T) -> Self {
+        match value.as_value() {
+            Ok(v) => self.value(path, v),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    pub fn null(self, path: &str) -> Self {
+        self.value(path, Value::Null)
+    }
+
+    pub fn bool(self, path: &str, value: bool) -> Self {
+        self.value(path, Value::Bool(value))
+    }
+
+    pub fn i32(self, path: &str, value: i32) -> Self {
+        self.value(path, Value::I32(value))
+    }
+
+    pub fn i64(self, path: &str, value: i64) -> Self {
+        self.value(path, Value::I64(value))
+    }
+
+    pub fn u32(self, path: &str, value: u32) -> Self {
+        self.value(path, Value::U32(value))
+    }
+
+    pub fn u64(self, path: &str, value: u64) -> Self {
+        self.value(path, Value::U64(value))
+    }
+
+    pub fn f64(self, path: &str, value: f64) -> Self {
+        self.value(path, Value::F64(value))
+    }
+
+    pub fn decimal(self, path: &str, value: Decimal) -> Self {
+        self.value(path, Value::Decimal(value))
+    }
+
+    pub fn string(self, path: &str, value: &str) -> Self {
+        self.value(path, Value::String(value.to_string()))
+    }
+
+    pub fn binary(self, path: &str, value: Vec<u8>) -> Self {
+        self.value(path, Value::Binary(value))
+    }
+
+    pub fn date(self, path: &str, value: Date) -> Self {
+        self.value(path, Value::Date(value))
+    }
+
+    pub fn time(self, path: &str, value: Time) -> Self {
+        self.value(path, Value::Time(value))
+    }
+
+    pub fn datetime(self, path: &str, value: DateTime) -> Self {
+        self.value(path, Value::DateTime(value))
+    }
+
+    pub fn year_month(self, path: &str, value: YearMonth) -> Self {
+        self.value(path, Value::YearMonth(value))
+    }
+
+    /// 在指定路径设置由子构建器生成的对象
+    pub fn child(self, path: &str, builder: ValueBuilder) -> Self {
+        match builder.build() {
+            Ok(v) => self.value(path, v),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// 完成构建，返回构建过程中的第一个异常
+    pub fn build(self) -> Result<Value> {
+        match self.error {
+            Some(e) => Err(e),
+            None => OK(self.value),
+        }
+    }
+
+    fn fail(mut self, e: AppError) -> Self {
+        if self.error.is_none() {
+            self.error = Some(e);
+        }
+        self
+    }
+}
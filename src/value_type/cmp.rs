@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::main::Value;
+
+/// 按数据内容比较两个Value对象，与Ord的规则一致
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Value对象的全序比较规则
+///
+/// * 不同种类的数据按Null < Bool < 数值 < String < Binary < 日期 < Time < Array < Object排序
+/// * 数值类型忽略位宽按数值大小比较，如I32(1)、U64(1)、F64(1.0)及Decimal(1.00)均相等，
+///   浮点数按其最短十进制表示与Decimal比较，因此F64(0.1)与Decimal(0.1)相等；
+///   NaN之间相等且大于其它数值，-0.0与0.0相等
+/// * Date、DateTime及YearMonth统称为日期，按其起始时刻先后排序，
+///   起始时刻相同时按Date < DateTime < YearMonth排序，不同类型的日期不会相等
+/// * Array按元素依次比较，Object按排序后的键值对依次比较
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (kind(self), kind(other));
+        if a != b {
+            return a.cmp(&b);
+        }
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Binary(a), Value::Binary(b)) => a.cmp(b),
+            (Value::Time(a), Value::Time(b)) => a.to_chrono_time().cmp(&b.to_chrono_time()),
+            (Value::Array(a), Value::Array(b)) => a.iter().cmp(b.iter()),
+            (Value::Object(a), Value::Object(b)) => a.iter().cmp(b.iter()),
+            (a, b) => match (as_number(a), as_number(b)) {
+                (Some(x), Some(y)) => cmp_number(&x, &y),
+                _ => calendar(a).cmp(&calendar(b)),
+            },
+        }
+    }
+}
+
+/// 比较两个标量Value对象的大小，仅同类数据可比较，用于JSONPath过滤表达式
+///
+/// 数值类型之间、字符串之间等可比较，不同类型的日期之间、Array及Object均不可比较，此时返回None
+pub(crate) fn value_cmp(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Array(_), _) | (Value::Object(_), _) => None,
+        (Value::Date(_), Value::Date(_))
+        | (Value::DateTime(_), Value::DateTime(_))
+        | (Value::YearMonth(_), Value::YearMonth(_)) => Some(a.cmp(b)),
+        (Value::Date(_) | Value::DateTime(_) | Value::YearMonth(_), _) => None,
+        _ if kind(a) == kind(b) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// 数据种类的排列顺序
+pub(super) fn kind(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::I32(_)
+        | Value::I64(_)
+        | Value::U32(_)
+        | Value::U64(_)
+        | Value::F32(_)
+        | Value::F64(_)
+        | Value::Decimal(_) => 2,
+        Value::String(_) => 3,
+        Value::Binary(_) => 4,
+        Value::Date(_) | Value::DateTime(_) | Value::YearMonth(_) => 5,
+        Value::Time(_) => 6,
+        Value::Array(_) => 7,
+        Value::Object(_) => 8,
+    }
+}
+
+/// 日期的起始时刻及同一时刻下的排列顺序
+pub(super) fn calendar(value: &Value) -> Option<(chrono::NaiveDateTime, u8)> {
+    match value {
+        Value::Date(v) => Some((v.to_chrono_date().and_hms_opt(0, 0, 0)?, 0)),
+        Value::DateTime(v) => Some((v.to_chrono_datetime(), 1)),
+        Value::YearMonth(v) => Some((v.to_chrono_date().and_hms_opt(0, 0, 0)?, 2)),
+        _ => None,
+    }
+}
+
+/// 统一表示后的数值
+///
+/// 整数及值为整数的浮点数、Decimal表示为Int，其余可由最短十进制表示精确转换的浮点数及Decimal表示为Decimal，
+/// 仅NaN、无穷大及超出Decimal范围的浮点数表示为Float，因此不同表示的数值不会相等
+pub(super) enum Number {
+    Int(i128),
+    Decimal(Decimal),
+    Float(f64),
+}
+
+pub(super) fn as_number(value: &Value) -> Option<Number> {
+    match value {
+        Value::I32(v) => Some(Number::Int(*v as i128)),
+        Value::I64(v) => Some(Number::Int(*v as i128)),
+        Value::U32(v) => Some(Number::Int(*v as i128)),
+        Value::U64(v) => Some(Number::Int(*v as i128)),
+        Value::F32(v) => Some(float(*v as f64, v.to_string().as_str())),
+        Value::F64(v) => Some(float(*v, v.to_string().as_str())),
+        Value::Decimal(v) => Some(if v.fract().is_zero() {
+            Number::Int(v.to_i128().unwrap_or_default())
+        } else {
+            Number::Decimal(v.normalize())
+        }),
+        _ => None,
+    }
+}
+
+/// text为浮点数的最短十进制表示
+fn float(v: f64, text: &str) -> Number {
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0;
+    if v.is_finite() && v.fract() == 0.0 && (-LIMIT..LIMIT).contains(&v) {
+        Number::Int(v as i128)
+    } else if let Ok(d) = Decimal::from_str_exact(text) {
+        Number::Decimal(d.normalize())
+    } else {
+        Number::Float(v)
+    }
+}
+
+fn cmp_number(a: &Number, b: &Number) -> Ordering {
+    match (a, b) {
+        (Number::Int(x), Number::Int(y)) => x.cmp(y),
+        (Number::Decimal(x), Number::Decimal(y)) => x.cmp(y),
+        (Number::Float(x), Number::Float(y)) => cmp_float(*x, *y),
+        (Number::Int(x), Number::Decimal(y)) => match Decimal::try_from_i128_with_scale(*x, 0) {
+            Ok(x) => x.cmp(y),
+            Err(_) => x.cmp(&0),
+        },
+        (Number::Int(x), Number::Float(y)) => cmp_float(*x as f64, *y).then(Ordering::Less),
+        (Number::Decimal(x), Number::Float(y)) => {
+            cmp_float(x.to_f64().unwrap_or_default(), *y).then(Ordering::Less)
+        }
+        (a, b) => cmp_number(b, a).reverse(),
+    }
+}
+
+/// 浮点数全序比较，NaN之间相等且大于其它数值
+fn cmp_float(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
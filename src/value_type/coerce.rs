@@ -0,0 +1,328 @@
+use std::fmt::Display;
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use crate::{
+    date::{Date, DateTime, Time, YearMonth},
+    error::{AppError, ERR_CAST},
+    types::StringExt,
+    Result, OK,
+};
+
+use super::main::Value;
+
+/// 宽松转换规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoercionRule {
+    /// 将字符串解析为数值，如"42"、"-1.5"
+    NumericString,
+    /// 将字符串解析为布尔值，支持StringExt::str_to_bool中的取值，如"yes"、"off"
+    BoolWord,
+    /// 将字符串解析为日期类型，日期时间中的日期与时间可使用空格或T分隔
+    DateString,
+    /// 将整数转换为浮点数，仅在可精确表示时转换
+    WidenInteger,
+    /// 将浮点数及Decimal转换为整数，仅在值为整数时转换
+    NarrowFloat,
+}
+
+impl CoercionRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoercionRule::NumericString => "numeric_string",
+            CoercionRule::BoolWord => "bool_word",
+            CoercionRule::DateString => "date_string",
+            CoercionRule::WidenInteger => "widen_integer",
+            CoercionRule::NarrowFloat => "narrow_float",
+        }
+    }
+}
+
+impl Display for CoercionRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 宽松转换策略，用于Value::coerce_xx方法及ContextTrait::coerced
+///
+/// 默认启用所有规则，可通过strict获取不启用任何规则的策略，此时与Value::as_xx方法的行为基本一致。
+/// 转换失败时返回ERR_CAST异常，异常信息及上下文中的rule为失败或未启用的规则名称
+///
+/// ```ignore
+/// let policy = CoercionPolicy::new().rule(CoercionRule::NarrowFloat, false);
+/// let port = Value::String("8080".to_string()).coerce_i64(&policy)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoercionPolicy {
+    rules: Vec<CoercionRule>,
+}
+
+impl Default for CoercionPolicy {
+    fn default() -> Self {
+        CoercionPolicy {
+            rules: vec![
+                CoercionRule::NumericString,
+                CoercionRule::BoolWord,
+                CoercionRule::DateString,
+                CoercionRule::WidenInteger,
+                CoercionRule::NarrowFloat,
+            ],
+        }
+    }
+}
+
+impl CoercionPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 不启用任何规则的策略
+    pub fn strict() -> Self {
+        CoercionPolicy { rules: vec![] }
+    }
+
+    /// 启用或禁用指定规则
+    pub fn rule(mut self, rule: CoercionRule, enabled: bool) -> Self {
+        self.rules.retain(|x| *x != rule);
+        if enabled {
+            self.rules.push(rule);
+        }
+        self
+    }
+
+    /// 检查是否启用了指定规则
+    pub fn is_enabled(&self, rule: CoercionRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// 检查规则是否启用，未启用时返回异常
+    fn check(&self, value: &Value, rule: CoercionRule, ty: &str) -> Result<()> {
+        if self.is_enabled(rule) {
+            OK(())
+        } else {
+            Err(ERR_CAST
+                .msg_detail(
+                    format!(
+                        "Value数据[{:?}]不能转换为{}类型，未启用规则[{}]",
+                        value, ty, rule
+                    )
+                    .as_str(),
+                )
+                .context_value("rule".to_string(), Value::String(rule.to_string())))
+        }
+    }
+}
+
+/// 规则已启用但转换失败时的异常
+fn rule_error(value: &Value, rule: CoercionRule, ty: &str, reason: &str) -> AppError {
+    ERR_CAST
+        .msg_detail(
+            format!(
+                "Value数据[{:?}]按规则[{}]转换为{}类型失败：{}",
+                value, rule, ty, reason
+            )
+            .as_str(),
+        )
+        .context_value("rule".to_string(), Value::String(rule.to_string()))
+}
+
+impl Value {
+    /// 按转换策略转换为bool类型
+    pub fn coerce_bool(&self, policy: &CoercionPolicy) -> Result<bool> {
+        match self {
+            Value::String(s) => {
+                policy.check(self, CoercionRule::BoolWord, "bool")?;
+                s.trim().str_to_bool().map_err(|_| {
+                    rule_error(self, CoercionRule::BoolWord, "bool", "不是合法的布尔值")
+                })
+            }
+            _ => self.as_bool(),
+        }
+    }
+
+    /// 按转换策略转换为i32类型
+    pub fn coerce_i32(&self, policy: &CoercionPolicy) -> Result<i32> {
+        self.coerce_integer(policy, "i32")?.as_i32()
+    }
+
+    /// 按转换策略转换为i64类型
+    pub fn coerce_i64(&self, policy: &CoercionPolicy) -> Result<i64> {
+        self.coerce_integer(policy, "i64")?.as_i64()
+    }
+
+    /// 按转换策略转换为u64类型
+    pub fn coerce_u64(&self, policy: &CoercionPolicy) -> Result<u64> {
+        self.coerce_integer(policy, "u64")?.as_u64()
+    }
+
+    /// 按转换策略转换为f64类型
+    pub fn coerce_f64(&self, policy: &CoercionPolicy) -> Result<f64> {
+        match self {
+            Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_) => {
+                policy.check(self, CoercionRule::WidenInteger, "f64")?;
+                let v = self.as_decimal()?.to_i128().unwrap_or_default();
+                let res = v as f64;
+                // 由u64及i64转换的浮点数不会超出i128的范围，可直接比较转换前后是否一致
+                if res as i128 == v {
+                    OK(res)
+                } else {
+                    Err(rule_error(
+                        self,
+                        CoercionRule::WidenInteger,
+                        "f64",
+                        "超出浮点数可精确表示的范围",
+                    ))
+                }
+            }
+            Value::String(s) => {
+                policy.check(self, CoercionRule::NumericString, "f64")?;
+                match s.trim().parse::<f64>() {
+                    Ok(v) if v.is_finite() => OK(v),
+                    _ => Err(rule_error(
+                        self,
+                        CoercionRule::NumericString,
+                        "f64",
+                        "不是合法的数值",
+                    )),
+                }
+            }
+            _ => self.as_f64(),
+        }
+    }
+
+    /// 按转换策略转换为Decimal类型，数值类型均可直接转换
+    pub fn coerce_decimal(&self, policy: &CoercionPolicy) -> Result<Decimal> {
+        match self {
+            Value::String(_) => {
+                policy.check(self, CoercionRule::NumericString, "Decimal")?;
+                self.as_decimal().map_err(|_| {
+                    rule_error(
+                        self,
+                        CoercionRule::NumericString,
+                        "Decimal",
+                        "不是合法的数值",
+                    )
+                })
+            }
+            _ => self.as_decimal(),
+        }
+    }
+
+    /// 按转换策略转换为Date类型
+    pub fn coerce_date(&self, policy: &CoercionPolicy) -> Result<Date> {
+        match self {
+            Value::String(s) => {
+                policy.check(self, CoercionRule::DateString, "Date")?;
+                Date::parse_str(s.trim()).map_err(|_| {
+                    rule_error(self, CoercionRule::DateString, "Date", "格式应为%Y-%m-%d")
+                })
+            }
+            _ => self.as_date(),
+        }
+    }
+
+    /// 按转换策略转换为Time类型
+    pub fn coerce_time(&self, policy: &CoercionPolicy) -> Result<Time> {
+        match self {
+            Value::String(s) => {
+                policy.check(self, CoercionRule::DateString, "Time")?;
+                Time::parse_str(s.trim()).map_err(|_| {
+                    rule_error(self, CoercionRule::DateString, "Time", "格式应为%H:%M:%S")
+                })
+            }
+            _ => self.as_time(),
+        }
+    }
+
+    /// 按转换策略转换为DateTime类型，仅包含日期的字符串转换为当天零点
+    pub fn coerce_datetime(&self, policy: &CoercionPolicy) -> Result<DateTime> {
+        match self {
+            Value::String(s) => {
+                policy.check(self, CoercionRule::DateString, "DateTime")?;
+                let s = s.trim();
+                let res = if s.len() == 10 {
+                    DateTime::parse_str(format!("{} 00:00:00", s).as_str())
+                } else {
+                    DateTime::parse_str(s.replacen('T', " ", 1).as_str())
+                };
+                res.map_err(|_| {
+                    rule_error(
+                        self,
+                        CoercionRule::DateString,
+                        "DateTime",
+                        "格式应为%Y-%m-%d %H:%M:%S",
+                    )
+                })
+            }
+            _ => self.as_datetime(),
+        }
+    }
+
+    /// 按转换策略转换为YearMonth类型
+    pub fn coerce_year_month(&self, policy: &CoercionPolicy) -> Result<YearMonth> {
+        match self {
+            Value::String(s) => {
+                policy.check(self, CoercionRule::DateString, "YearMonth")?;
+                YearMonth::parse_str(s.trim()).map_err(|_| {
+                    rule_error(self, CoercionRule::DateString, "YearMonth", "格式应为%Y-%m")
+                })
+            }
+            _ => self.as_year_month(),
+        }
+    }
+
+    /// 按转换策略转换为整数类型的Value，由调用方完成位宽检查
+    fn coerce_integer(&self, policy: &CoercionPolicy, ty: &str) -> Result<Value> {
+        match self {
+            Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_) => OK(self.clone()),
+            Value::F32(_) | Value::F64(_) | Value::Decimal(_) => {
+                policy.check(self, CoercionRule::NarrowFloat, ty)?;
+                let v = self
+                    .as_decimal()
+                    .map_err(|_| rule_error(self, CoercionRule::NarrowFloat, ty, "不是有限数值"))?;
+                if !v.fract().is_zero() {
+                    return Err(rule_error(
+                        self,
+                        CoercionRule::NarrowFloat,
+                        ty,
+                        "存在小数部分",
+                    ));
+                }
+                match (v.to_i64(), v.to_u64()) {
+                    (Some(x), _) => OK(Value::I64(x)),
+                    (_, Some(x)) => OK(Value::U64(x)),
+                    _ => Err(rule_error(
+                        self,
+                        CoercionRule::NarrowFloat,
+                        ty,
+                        "超出整数范围",
+                    )),
+                }
+            }
+            Value::String(s) => {
+                policy.check(self, CoercionRule::NumericString, ty)?;
+                let s = s.trim();
+                if let Ok(v) = s.parse::<i64>() {
+                    return OK(Value::I64(v));
+                }
+                if let Ok(v) = s.parse::<u64>() {
+                    return OK(Value::U64(v));
+                }
+                match Value::String(s.to_string()).as_decimal() {
+                    Ok(v) => Value::Decimal(v).coerce_integer(policy, ty),
+                    Err(_) => Err(rule_error(
+                        self,
+                        CoercionRule::NumericString,
+                        ty,
+                        "不是合法的数值",
+                    )),
+                }
+            }
+            _ => {
+                Err(ERR_CAST
+                    .msg_detail(format!("Value数据[{:?}]不能转换为{}类型", self, ty).as_str()))
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+use crate::date::{Date, DateTime, Time, YearMonth};
+
+use super::{
+    main::Value,
+    token::{DATETIME_TOKEN, DATE_TOKEN, DECIMAL_TOKEN, TIME_TOKEN, VALUE_TOKEN, YEARMONTH_TOKEN},
+};
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(VALUE_TOKEN, ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("任意可转换为内置Value的数据")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i8<E>(self, v: i8) -> std::result::Result<Self::Value, E> {
+        Ok(Value::I32(v as i32))
+    }
+
+    fn visit_i16<E>(self, v: i16) -> std::result::Result<Self::Value, E> {
+        Ok(Value::I32(v as i32))
+    }
+
+    fn visit_i32<E>(self, v: i32) -> std::result::Result<Self::Value, E> {
+        Ok(Value::I32(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::I64(v))
+    }
+
+    fn visit_u8<E>(self, v: u8) -> std::result::Result<Self::Value, E> {
+        Ok(Value::U32(v as u32))
+    }
+
+    fn visit_u16<E>(self, v: u16) -> std::result::Result<Self::Value, E> {
+        Ok(Value::U32(v as u32))
+    }
+
+    fn visit_u32<E>(self, v: u32) -> std::result::Result<Self::Value, E> {
+        Ok(Value::U32(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::U64(v))
+    }
+
+    fn visit_f32<E>(self, v: f32) -> std::result::Result<Self::Value, E> {
+        Ok(Value::F32(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::F64(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> std::result::Result<Self::Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Binary(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Binary(v))
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut arr = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(v) = seq.next_element::<Value>()? {
+            arr.push(v);
+        }
+        Ok(Value::Array(arr))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut obj = BTreeMap::new();
+        let first_key = match map.next_key::<String>()? {
+            Some(k) => k,
+            None => return Ok(Value::Object(obj)),
+        };
+        let typed = match first_key.as_str() {
+            DATE_TOKEN => Some(
+                Date::parse_str(map.next_value::<String>()?.as_str())
+                    .map(Value::Date)
+                    .map_err(A::Error::custom)?,
+            ),
+            TIME_TOKEN => Some(
+                Time::parse_str(map.next_value::<String>()?.as_str())
+                    .map(Value::Time)
+                    .map_err(A::Error::custom)?,
+            ),
+            DATETIME_TOKEN => Some(
+                DateTime::parse_str(map.next_value::<String>()?.as_str())
+                    .map(Value::DateTime)
+                    .map_err(A::Error::custom)?,
+            ),
+            YEARMONTH_TOKEN => Some(
+                YearMonth::parse_str(map.next_value::<String>()?.as_str())
+                    .map(Value::YearMonth)
+                    .map_err(A::Error::custom)?,
+            ),
+            DECIMAL_TOKEN => Some(
+                Value::String(map.next_value::<String>()?)
+                    .as_decimal()
+                    .map(Value::Decimal)
+                    .map_err(A::Error::custom)?,
+            ),
+            _ => None,
+        };
+        if let Some(v) = typed {
+            return Ok(v);
+        }
+        obj.insert(first_key, map.next_value::<Value>()?);
+        while let Some((k, v)) = map.next_entry::<String, Value>()? {
+            obj.insert(k, v);
+        }
+        Ok(Value::Object(obj))
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::btree_map;
+
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, SeqDeserializer, StringDeserializer},
+        DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, Unexpected, VariantAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+
+use crate::{
+    error::{AppError, ERR_DESERIALIZE},
+    Result, OK,
+};
+
+use super::{
+    main::{decimal_to_f64, Value},
+    token::{DATETIME_TOKEN, DATE_TOKEN, DECIMAL_TOKEN, TIME_TOKEN, VALUE_TOKEN, YEARMONTH_TOKEN},
+};
+
+/// 将内置Value转换为任意实现了Deserialize的对象
+///
+/// 日期类型可直接转换为date模块中对应的类型，Binary类型可转换为字节数组
+pub fn from_value<'de, T>(value: &'de Value) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    T::deserialize(value)
+}
+
+impl<'de> IntoDeserializer<'de, AppError> for &'de Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for &'de Value {
+    type Error = AppError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(*v),
+            Value::I32(v) => visitor.visit_i32(*v),
+            Value::I64(v) => visitor.visit_i64(*v),
+            Value::U32(v) => visitor.visit_u32(*v),
+            Value::U64(v) => visitor.visit_u64(*v),
+            Value::F32(v) => visitor.visit_f32(*v),
+            Value::F64(v) => visitor.visit_f64(*v),
+            Value::Decimal(v) => visitor.visit_string(v.to_string()),
+            Value::Date(v) => visitor.visit_string(v.to_string()),
+            Value::Time(v) => visitor.visit_string(v.to_string()),
+            Value::DateTime(v) => visitor.visit_string(v.to_string()),
+            Value::YearMonth(v) => visitor.visit_string(v.to_string()),
+            Value::Binary(v) => visitor.visit_borrowed_bytes(v),
+            Value::String(v) => visitor.visit_borrowed_str(v),
+            Value::Array(v) => visitor.visit_seq(SeqDeserializer::new(v.iter())),
+            Value::Object(v) => visitor.visit_map(ObjectAccess::new(v)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        if name != VALUE_TOKEN {
+            return visitor.visit_newtype_struct(self);
+        }
+        match self {
+            Value::Date(v) => visitor.visit_map(TypedAccess::new(DATE_TOKEN, v.to_string())),
+            Value::Time(v) => visitor.visit_map(TypedAccess::new(TIME_TOKEN, v.to_string())),
+            Value::DateTime(v) => {
+                visitor.visit_map(TypedAccess::new(DATETIME_TOKEN, v.to_string()))
+            }
+            Value::YearMonth(v) => {
+                visitor.visit_map(TypedAccess::new(YEARMONTH_TOKEN, v.to_string()))
+            }
+            Value::Decimal(v) => visitor.visit_map(TypedAccess::new(DECIMAL_TOKEN, v.to_string())),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Binary(v) => visitor.visit_seq(SeqDeserializer::new(v.iter().copied())),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::String(v) => visitor.visit_borrowed_bytes(v.as_bytes()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::String(v) => visitor.visit_enum(EnumDeserializer {
+                variant: v,
+                value: None,
+            }),
+            Value::Object(v) if v.len() == 1 => {
+                let (variant, value) = v.iter().next().unwrap();
+                visitor.visit_enum(EnumDeserializer {
+                    variant,
+                    value: Some(value),
+                })
+            }
+            _ => Err(ERR_DESERIALIZE.msg_detail(
+                format!(
+                    "Value数据[{:?}]不能转换为枚举类型{}，需为字符串或仅包含一个键的Object",
+                    self, name
+                )
+                .as_str(),
+            )),
+        }
+    }
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_f64(visitor)
+    }
+
+    /// Decimal仅在可精确转换为f64时才能反序列化为浮点数
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Value::Decimal(v) => visitor.visit_f64(decimal_to_f64(v)?),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char str string
+        unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// 用于遍历Object对象的MapAccess
+struct ObjectAccess<'de> {
+    iter: btree_map::Iter<'de, String, Value>,
+    value: Option<&'de Value>,
+}
+
+impl<'de> ObjectAccess<'de> {
+    fn new(map: &'de std::collections::BTreeMap<String, Value>) -> Self {
+        ObjectAccess {
+            iter: map.iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'de> {
+    type Error = AppError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((k, v)) => {
+                self.value = Some(v);
+                seed.deserialize(BorrowedStrDeserializer::<AppError>::new(k))
+                    .map(Some)
+            }
+            None => OK(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(v) => seed.deserialize(v),
+            None => Err(ERR_DESERIALIZE.msg_detail("Object数据在读取键之前读取了值")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+/// 用于传递日期类型的MapAccess，仅包含一个标记键及其字符串值
+struct TypedAccess {
+    token: Option<&'static str>,
+    value: Option<String>,
+}
+
+impl TypedAccess {
+    fn new(token: &'static str, value: String) -> Self {
+        TypedAccess {
+            token: Some(token),
+            value: Some(value),
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for TypedAccess {
+    type Error = AppError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.token.take() {
+            Some(token) => seed
+                .deserialize(BorrowedStrDeserializer::<AppError>::new(token))
+                .map(Some),
+            None => OK(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(v) => seed.deserialize(StringDeserializer::new(v)),
+            None => Err(ERR_DESERIALIZE.msg_detail("日期类型数据已被读取")),
+        }
+    }
+}
+
+/// 用于枚举类型的反序列化
+struct EnumDeserializer<'de> {
+    variant: &'de String,
+    value: Option<&'de Value>,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer<'de> {
+    type Error = AppError;
+    type Variant = VariantDeserializer<'de>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(BorrowedStrDeserializer::<AppError>::new(self.variant))?;
+        OK((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+struct VariantDeserializer<'de> {
+    value: Option<&'de Value>,
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer<'de> {
+    type Error = AppError;
+
+    fn unit_variant(self) -> Result<()> {
+        match self.value {
+            None | Some(Value::Null) => OK(()),
+            Some(v) => Err(serde::de::Error::invalid_type(
+                unexpected(v),
+                &"unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(v) => seed.deserialize(v),
+            None => Err(serde::de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(Value::Array(v)) => visitor.visit_seq(SeqDeserializer::new(v.iter())),
+            Some(v) => Err(serde::de::Error::invalid_type(
+                unexpected(v),
+                &"tuple variant",
+            )),
+            None => Err(serde::de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"tuple variant",
+            )),
+        }
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(Value::Object(v)) => visitor.visit_map(ObjectAccess::new(v)),
+            Some(v) => Err(serde::de::Error::invalid_type(
+                unexpected(v),
+                &"struct variant",
+            )),
+            None => Err(serde::de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"struct variant",
+            )),
+        }
+    }
+}
+
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Null => Unexpected::Unit,
+        Value::Bool(v) => Unexpected::Bool(*v),
+        Value::I32(v) => Unexpected::Signed(*v as i64),
+        Value::I64(v) => Unexpected::Signed(*v),
+        Value::U32(v) => Unexpected::Unsigned(*v as u64),
+        Value::U64(v) => Unexpected::Unsigned(*v),
+        Value::F32(v) => Unexpected::Float(*v as f64),
+        Value::F64(v) => Unexpected::Float(*v),
+        Value::Decimal(_) => Unexpected::Other("Decimal"),
+        Value::Date(_) => Unexpected::Other("Date"),
+        Value::Time(_) => Unexpected::Other("Time"),
+        Value::DateTime(_) => Unexpected::Other("DateTime"),
+        Value::YearMonth(_) => Unexpected::Other("YearMonth"),
+        Value::Binary(v) => Unexpected::Bytes(v),
+        Value::String(v) => Unexpected::Str(v),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    }
+}
@@ -14,6 +14,7 @@ impl Debug for Value {
             Self::U64(arg0) => f.debug_tuple("U64").field(arg0).finish(),
             Self::F32(arg0) => f.debug_tuple("F32").field(arg0).finish(),
             Self::F64(arg0) => f.debug_tuple("F64").field(arg0).finish(),
+            Self::Decimal(arg0) => f.debug_tuple("Decimal").field(arg0).finish(),
             Self::Date(arg0) => f.debug_tuple("Date").field(arg0).finish(),
             Self::Time(arg0) => f.debug_tuple("Time").field(arg0).finish(),
             Self::DateTime(arg0) => f.debug_tuple("DateTime").field(arg0).finish(),
@@ -37,6 +38,7 @@ impl Display for Value {
             Self::U64(arg0) => f.debug_tuple("U64").field(arg0).finish(),
             Self::F32(arg0) => f.debug_tuple("F32").field(arg0).finish(),
             Self::F64(arg0) => f.debug_tuple("F64").field(arg0).finish(),
+            Self::Decimal(arg0) => f.debug_tuple("Decimal").field(arg0).finish(),
             Self::Date(arg0) => f.debug_tuple("Date").field(arg0).finish(),
             Self::Time(arg0) => f.debug_tuple("Time").field(arg0).finish(),
             Self::DateTime(arg0) => f.debug_tuple("DateTime").field(arg0).finish(),
@@ -0,0 +1,273 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    bean::parse_index,
+    error::{AppError, ERR_DATA},
+    Result, OK,
+};
+
+use super::main::Value;
+
+/// 扁平化时数组下标的表示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayStyle {
+    /// 下标放在方括号中，如：a.b[0].c
+    Bracket,
+    /// 下标与键相同，使用分隔符连接，如：a.b.0.c
+    ///
+    /// 还原时所有非负整数格式的键均视为数组下标
+    Separator,
+}
+
+/// 路径中的一段
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl Value {
+    /// 将多层级的对象转换为单层的键值对，可用于环境变量、properties文件、表单及Redis Hash等场景
+    ///
+    /// 空的Array及Object作为值保留，以便还原；根节点不是Array或Object时，返回键为空字符串的单个键值对。
+    /// 键中包含分隔符或方括号时，扁平化后的结果可能无法准确还原
+    ///
+    /// ```ignore
+    /// let map = value!({"a": {"b": [{"c": 1}]}}).flatten(".", ArrayStyle::Bracket);
+    /// assert_eq!(map.get("a.b[0].c"), Some(&Value::I32(1)));
+    /// ```
+    pub fn flatten(&self, separator: &str, array_style: ArrayStyle) -> BTreeMap<String, Value> {
+        let mut res = BTreeMap::new();
+        flatten_into(self, String::new(), separator, array_style, &mut res);
+        res
+    }
+
+    /// 将单层的键值对还原为多层级的对象，为Value::flatten的逆操作
+    ///
+    /// 键之间存在冲突时返回ERR_DATA异常，上下文中的key为冲突的键，如：同时存在a及a.b且a不是Object、
+    /// 同一层级同时以数组下标及对象键的方式访问；数组中缺失的下标以Null填充，
+    /// 为避免恶意数据占用大量内存，下标不能超过键值对的数量
+    pub fn unflatten(
+        map: &BTreeMap<String, Value>,
+        separator: &str,
+        array_style: ArrayStyle,
+    ) -> Result<Value> {
+        let mut root = Node::Unset;
+        for (key, value) in map {
+            let segments = parse_key(key, separator, array_style).ok_or_else(|| {
+                ERR_DATA
+                    .msg_detail(format!("键[{}]格式不正确", key).as_str())
+                    .context_value("key".to_string(), Value::String(key.clone()))
+            })?;
+            root.insert(&segments, value.clone(), map.len(), key)?;
+        }
+        OK(match root {
+            Node::Unset => Value::Object(BTreeMap::new()),
+            node => node.into_value(),
+        })
+    }
+}
+
+fn flatten_into(
+    value: &Value,
+    prefix: String,
+    separator: &str,
+    array_style: ArrayStyle,
+    res: &mut BTreeMap<String, Value>,
+) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}{}{}", prefix, separator, key)
+        }
+    };
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (k, v) in obj {
+                flatten_into(v, join(k), separator, array_style, res);
+            }
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            for (i, v) in arr.iter().enumerate() {
+                let key = match array_style {
+                    ArrayStyle::Bracket => format!("{}[{}]", prefix, i),
+                    ArrayStyle::Separator => join(i.to_string().as_str()),
+                };
+                flatten_into(v, key, separator, array_style, res);
+            }
+        }
+        _ => {
+            res.insert(prefix, value.clone());
+        }
+    }
+}
+
+/// 解析扁平化后的键，格式不正确时返回None
+fn parse_key(key: &str, separator: &str, array_style: ArrayStyle) -> Option<Vec<Segment>> {
+    if key.is_empty() {
+        return Some(vec![]);
+    }
+    let mut res = vec![];
+    for part in key.split(separator) {
+        match array_style {
+            ArrayStyle::Separator => res.push(match parse_index(part) {
+                Some(index) => Segment::Index(index),
+                None => Segment::Key(part.to_string()),
+            }),
+            ArrayStyle::Bracket => {
+                let (name, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+                // 以下标开头时不包含键，如：[0].a
+                if !name.is_empty() || rest.is_empty() {
+                    res.push(Segment::Key(name.to_string()));
+                }
+                while !rest.is_empty() {
+                    let end = rest.find(']')?;
+                    res.push(Segment::Index(parse_index(rest.get(1..end)?)?));
+                    rest = &rest[end + 1..];
+                    if !rest.is_empty() && !rest.starts_with('[') {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+    Some(res)
+}
+
+/// 以点号及方括号格式的路径在扁平化的键值对中查找数据
+///
+/// 优先查找与路径完全相同的键，其次查找与路径前缀相同的键，并在其值中继续按剩余路径查找，
+/// 如：a.b[0].c可以在a.b对应的数组中查找
+pub(crate) fn lookup_flat<'a>(map: &'a BTreeMap<String, Value>, key: &str) -> Option<&'a Value> {
+    if let Some(v) = map.get(key) {
+        return Some(v);
+    }
+    let bounds = key
+        .char_indices()
+        .filter(|(_, c)| *c == '.' || *c == '[')
+        .map(|(i, _)| i)
+        .rev();
+    for i in bounds {
+        let rest = key[i..].strip_prefix('.').unwrap_or(&key[i..]);
+        if let (Some(v), false) = (map.get(&key[..i]), rest.is_empty()) {
+            let res = parse_key(rest, ".", ArrayStyle::Bracket).and_then(|s| lookup(v, &s));
+            if res.is_some() {
+                return res;
+            }
+        }
+    }
+    None
+}
+
+fn lookup<'a>(value: &'a Value, segments: &[Segment]) -> Option<&'a Value> {
+    segments.iter().try_fold(value, |v, s| match (v, s) {
+        (Value::Object(obj), Segment::Key(k)) => obj.get(k),
+        (Value::Array(arr), Segment::Index(i)) => arr.get(*i),
+        (Value::Array(arr), Segment::Key(k)) => arr.get(parse_index(k)?),
+        _ => None,
+    })
+}
+
+/// 还原过程中的节点，Unset表示尚未写入数据
+enum Node {
+    Unset,
+    Leaf(Value),
+    Object(BTreeMap<String, Node>),
+    Array(Vec<Node>),
+}
+
+/// 键之间存在冲突时的异常，上下文中的key为冲突的键
+fn conflict(key: &str, msg: &str) -> AppError {
+    ERR_DATA
+        .msg_detail(format!("键[{}]{}", key, msg).as_str())
+        .context_value("key".to_string(), Value::String(key.to_string()))
+}
+
+impl Node {
+    fn insert(
+        &mut self,
+        segments: &[Segment],
+        value: Value,
+        limit: usize,
+        key: &str,
+    ) -> Result<()> {
+        let (first, rest) = match segments.split_first() {
+            Some(v) => v,
+            None => return self.set(value, limit, key),
+        };
+        match first {
+            Segment::Key(k) => {
+                if let Node::Unset = self {
+                    *self = Node::Object(BTreeMap::new());
+                }
+                match self {
+                    Node::Object(obj) => obj
+                        .entry(k.clone())
+                        .or_insert(Node::Unset)
+                        .insert(rest, value, limit, key),
+                    Node::Array(_) => Err(conflict(key, "以对象键的方式访问数组")),
+                    _ => Err(conflict(key, "与已存在的非对象数据冲突")),
+                }
+            }
+            Segment::Index(i) => {
+                if *i > limit {
+                    return Err(conflict(key, "中的数组下标超出范围"));
+                }
+                if let Node::Unset = self {
+                    *self = Node::Array(vec![]);
+                }
+                match self {
+                    Node::Array(arr) => {
+                        while arr.len() <= *i {
+                            arr.push(Node::Unset);
+                        }
+                        arr[*i].insert(rest, value, limit, key)
+                    }
+                    Node::Object(_) => Err(conflict(key, "以数组下标的方式访问对象")),
+                    _ => Err(conflict(key, "与已存在的非数组数据冲突")),
+                }
+            }
+        }
+    }
+
+    /// 写入数据，已存在相同类型的Array或Object时合并其中的数据
+    fn set(&mut self, value: Value, limit: usize, key: &str) -> Result<()> {
+        match (&*self, value) {
+            (Node::Unset, Value::Object(obj)) | (Node::Object(_), Value::Object(obj)) => {
+                if let Node::Unset = self {
+                    *self = Node::Object(BTreeMap::new());
+                }
+                for (k, v) in obj {
+                    self.insert(&[Segment::Key(k)], v, limit, key)?;
+                }
+                OK(())
+            }
+            (Node::Unset, Value::Array(arr)) | (Node::Array(_), Value::Array(arr)) => {
+                if let Node::Unset = self {
+                    *self = Node::Array(vec![]);
+                }
+                for (i, v) in arr.into_iter().enumerate() {
+                    self.insert(&[Segment::Index(i)], v, limit.max(i), key)?;
+                }
+                OK(())
+            }
+            (Node::Unset, v) => {
+                *self = Node::Leaf(v);
+                OK(())
+            }
+            _ => Err(conflict(key, "与已存在的数据冲突")),
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Node::Unset => Value::Null,
+            Node::Leaf(v) => v,
+            Node::Object(obj) => {
+                Value::Object(obj.into_iter().map(|(k, v)| (k, v.into_value())).collect())
+            }
+            Node::Array(arr) => Value::Array(arr.into_iter().map(|v| v.into_value()).collect()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::hash::{Hash, Hasher};
+
+use super::{
+    cmp::{as_number, calendar, kind, Number},
+    main::Value,
+};
+
+/// 与PartialEq的规则一致，相等的Value对象计算结果相同，如I32(1)与F64(1.0)
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        write_value(self, state);
+    }
+}
+
+impl Value {
+    /// 计算数据内容的指纹
+    ///
+    /// 与整数位宽无关，相等的Value对象指纹相同，且计算结果不受平台及程序版本影响，可用于持久化存储
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv64(FNV_OFFSET);
+        write_value(self, &mut hasher);
+        hasher.finish()
+    }
+}
+
+/// 仅通过write写入固定字节序的数据，保证不同平台下结果一致
+fn write_value<H: Hasher>(value: &Value, state: &mut H) {
+    state.write(&[kind(value)]);
+    match value {
+        Value::Null => {}
+        Value::Bool(v) => state.write(&[*v as u8]),
+        Value::String(v) => write_bytes(v.as_bytes(), state),
+        Value::Binary(v) => write_bytes(v, state),
+        Value::Time(v) => write_bytes(v.to_string().as_bytes(), state),
+        Value::Array(arr) => {
+            state.write(&(arr.len() as u64).to_le_bytes());
+            for v in arr {
+                write_value(v, state);
+            }
+        }
+        Value::Object(obj) => {
+            state.write(&(obj.len() as u64).to_le_bytes());
+            for (k, v) in obj {
+                write_bytes(k.as_bytes(), state);
+                write_value(v, state);
+            }
+        }
+        v => match (as_number(v), calendar(v)) {
+            (Some(Number::Int(x)), _) => {
+                state.write(&[0]);
+                state.write(&x.to_le_bytes());
+            }
+            (Some(Number::Decimal(x)), _) => {
+                state.write(&[1]);
+                state.write(&x.serialize());
+            }
+            (Some(Number::Float(x)), _) => {
+                let x = if x.is_nan() { f64::NAN } else { x };
+                state.write(&[2]);
+                state.write(&x.to_bits().to_le_bytes());
+            }
+            (None, Some((datetime, order))) => {
+                state.write(&[order]);
+                write_bytes(datetime.to_string().as_bytes(), state);
+            }
+            (None, None) => {}
+        },
+    }
+}
+
+fn write_bytes<H: Hasher>(bytes: &[u8], state: &mut H) {
+    state.write(&(bytes.len() as u64).to_le_bytes());
+    state.write(bytes);
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a哈希算法
+struct Fnv64(u64);
+
+impl Hasher for Fnv64 {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+/// 使用类似Json的语法构建内置Value对象
+///
+/// 值的位置可使用任意实现了AsValueTrait的表达式，日期、Decimal及Value::Binary等类型均保持原样，
+/// 键可使用字符串字面量或任意实现了ToString的表达式，表达式需放在括号中，如`(key): 1`
+///
+/// ```
+/// use knife_util::{value, Value};
+///
+/// let name = "knife";
+/// let value = value!({
+///     "name": name,
+///     "ports": [80, 443],
+///     "debug": false,
+///     "extra": null,
+/// });
+/// assert_eq!(value.as_object().unwrap().len(), 4);
+/// ```
+///
+/// # Panics
+///
+/// 值位置上的表达式调用`as_value`转换失败时会panic，基础类型、日期类型及Value对象不会转换失败，
+/// 包含外部数据等可能转换失败的表达式应先调用`as_value()?`转换为Value后再放入宏中
+#[macro_export]
+macro_rules! value {
+    // 以下为数组元素的解析规则，已解析的元素放在[]中
+    (@array [$($elems:expr,)*]) => {
+        ::std::vec![$($elems,)*]
+    };
+
+    (@array [$($elems:expr),*]) => {
+        ::std::vec![$($elems),*]
+    };
+
+    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!(null)] $($rest)*)
+    };
+
+    (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!([$($array)*])] $($rest)*)
+    };
+
+    (@array [$($elems:expr,)*] {$($object:tt)*} $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!({$($object)*})] $($rest)*)
+    };
+
+    (@array [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($next),] $($rest)*)
+    };
+
+    (@array [$($elems:expr,)*] $last:expr) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($last)])
+    };
+
+    (@array [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)*] $($rest)*)
+    };
+
+    // 以下为Object键值对的解析规则，依次为(已解析的键) (待解析的内容) (待解析内容的副本)
+    (@object $object:ident () () ()) => {};
+
+    (@object $object:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
+        let _ = $object.insert(::std::string::ToString::to_string(&$($key)+), $value);
+        $crate::value!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    (@object $object:ident [$($key:tt)+] ($value:expr)) => {
+        let _ = $object.insert(::std::string::ToString::to_string(&$($key)+), $value);
+    };
+
+    (@object $object:ident ($($key:tt)+) (: null $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!(null)) $($rest)*);
+    };
+
+    (@object $object:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!([$($array)*])) $($rest)*);
+    };
+
+    (@object $object:ident ($($key:tt)+) (: {$($map:tt)*} $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!({$($map)*})) $($rest)*);
+    };
+
+    (@object $object:ident ($($key:tt)+) (: $value:expr , $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!($value)) , $($rest)*);
+    };
+
+    (@object $object:ident ($($key:tt)+) (: $value:expr) $copy:tt) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!($value)));
+    };
+
+    (@object $object:ident () (($key:expr) : $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object ($key) (: $($rest)*) (: $($rest)*));
+    };
+
+    (@object $object:ident ($($key:tt)*) ($tt:tt $($rest:tt)*) $copy:tt) => {
+        $crate::value!(@object $object ($($key)* $tt) ($($rest)*) ($($rest)*));
+    };
+
+    // 以下为对外使用的规则
+    (null) => {
+        $crate::Value::Null
+    };
+
+    ([]) => {
+        $crate::Value::Array(::std::vec::Vec::new())
+    };
+
+    ([ $($tt:tt)+ ]) => {
+        $crate::Value::Array($crate::value!(@array [] $($tt)+))
+    };
+
+    ({}) => {
+        $crate::Value::Object(::std::collections::BTreeMap::new())
+    };
+
+    ({ $($tt:tt)+ }) => {
+        $crate::Value::Object({
+            let mut object = ::std::collections::BTreeMap::new();
+            $crate::value!(@object object () ($($tt)+) ($($tt)+));
+            object
+        })
+    };
+
+    ($other:expr) => {
+        $crate::bean::AsValueTrait::as_value(&$other)
+            .expect("value!宏中的表达式无法转换为内置Value")
+    };
+}
@@ -1,4 +1,6 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, str::FromStr};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::{
     date::{Date, DateTime, Time, YearMonth},
@@ -10,8 +12,7 @@ use crate::{
 /// 用于处理程序内置对象的工具
 ///
 /// 并负责对Json、Yaml、Toml等格式的对象进行处理及转换
-/// 注意的是内置对象不支持反序列化操作
-/// 如有该操作需求，需通过配置文件方式进行序列化/反序列化操作后进行数据合并
+/// 支持序列化/反序列化操作，可通过from_value将内置对象直接转换为实现了Deserialize的对象
 #[derive(Clone)]
 pub enum Value {
     Null,
@@ -22,6 +23,9 @@ pub enum Value {
     U64(u64),
     F32(f32),
     F64(f64),
+    /// Json、Yaml等格式中的数值按f64处理，为避免丢失精度，
+    /// Decimal转换为这些格式或由其它Serializer序列化时均输出为字符串
+    Decimal(Decimal),
     Date(Date),
     Time(Time),
     DateTime(DateTime),
@@ -62,6 +66,7 @@ impl Value {
             Value::U64(v) => OK(v == 0),
             Value::F32(v) => OK(v == 0.0),
             Value::F64(v) => OK(v == 0.0),
+            Value::Decimal(v) => OK(v.is_zero()),
             _ => {
                 Err(ERR_CAST
                     .msg_detail(format!("Value数据[{:?}]无法处理is_zero操作", self).as_str()))
@@ -147,12 +152,31 @@ impl Value {
         match *self {
             Value::F32(v) => OK(v.cast_to_f64()?),
             Value::F64(v) => OK(v),
+            Value::Decimal(v) => decimal_to_f64(&v),
             _ => {
                 Err(ERR_CAST.msg_detail(format!("Value数据[{:?}]不能转换为f64类型", self).as_str()))
             }
         }
     }
 
+    /// 转换为Decimal类型，整数及字符串可精确转换，浮点数按其最短十进制表示转换
+    pub fn as_decimal(&self) -> Result<Decimal> {
+        let res = match self {
+            Value::Decimal(v) => Some(*v),
+            Value::I32(v) => Some(Decimal::from(*v)),
+            Value::I64(v) => Some(Decimal::from(*v)),
+            Value::U32(v) => Some(Decimal::from(*v)),
+            Value::U64(v) => Some(Decimal::from(*v)),
+            Value::F32(v) if v.is_finite() => Decimal::from_str(v.to_string().as_str()).ok(),
+            Value::F64(v) if v.is_finite() => Decimal::from_str(v.to_string().as_str()).ok(),
+            Value::String(v) => Decimal::from_str_exact(v.trim()).ok(),
+            _ => None,
+        };
+        res.ok_or_else(|| {
+            ERR_CAST.msg_detail(format!("Value数据[{:?}]不能转换为Decimal类型", self).as_str())
+        })
+    }
+
     pub fn as_binary(&self) -> Result<&Vec<u8>> {
         match *self {
             Value::Binary(ref v) => OK(v),
@@ -201,12 +225,9 @@ impl Value {
         }
     }
 
-    #[allow(clippy::cast_ref_to_mut)]
-    pub fn as_object_mut(&self) -> Result<&mut BTreeMap<String, Value>> {
+    pub fn as_object_mut(&mut self) -> Result<&mut BTreeMap<String, Value>> {
         match *self {
-            Value::Object(ref v) => OK(unsafe {
-                &mut *(v as *const BTreeMap<String, Value> as *mut BTreeMap<String, Value>)
-            }),
+            Value::Object(ref mut v) => OK(v),
             _ => Err(ERR_CAST.msg_detail(
                 format!(
                     "Value数据[{:?}]不能转换为&mut BTreeMap<String, Value>类型",
@@ -259,3 +280,12 @@ impl Value {
         }
     }
 }
+
+/// 将Decimal转换为f64，无法精确表示时返回异常
+pub(crate) fn decimal_to_f64(value: &Decimal) -> Result<f64> {
+    match value.to_f64() {
+        Some(v) if Decimal::from_str(v.to_string().as_str()).ok() == Some(*value) => OK(v),
+        _ => Err(ERR_CAST
+            .msg_detail(format!("Decimal数据[{}]转换为f64类型时将丢失精度", value).as_str())),
+    }
+}
@@ -1,8 +1,30 @@
 //! Value内置对象
 //!
 //! 实现对任意对象间的数据转换
+mod builder;
+mod cmp;
+mod coerce;
+mod de;
+mod deserializer;
 mod display;
+mod flatten;
+mod hash;
+mod macros;
 mod main;
 mod ser;
+mod serializer;
+mod shared;
+mod tests;
+mod token;
 
+pub use builder::ValueBuilder;
+pub(crate) use cmp::value_cmp;
+pub use coerce::{CoercionPolicy, CoercionRule};
+pub use deserializer::from_value;
+pub(crate) use flatten::lookup_flat;
+pub use flatten::ArrayStyle;
 pub use main::Value;
+pub use serializer::to_value;
+pub(crate) use shared::Node;
+pub use shared::SharedValue;
+pub(crate) use token::{serialize_tagged, DATETIME_TOKEN, DATE_TOKEN, TIME_TOKEN, YEARMONTH_TOKEN};
@@ -1,6 +1,9 @@
 use serde::Serialize;
 
-use super::main::Value;
+use super::{
+    main::Value,
+    token::{serialize_tagged, DECIMAL_TOKEN},
+};
 
 impl Serialize for Value {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -16,10 +19,11 @@ impl Serialize for Value {
             Value::U64(v) => serializer.serialize_u64(*v),
             Value::F32(v) => serializer.serialize_f32(*v),
             Value::F64(v) => serializer.serialize_f64(*v),
-            Value::Date(v) => serializer.serialize_str(v.to_string().as_str()),
-            Value::Time(v) => serializer.serialize_str(v.to_string().as_str()),
-            Value::DateTime(v) => serializer.serialize_str(v.to_string().as_str()),
-            Value::YearMonth(v) => serializer.serialize_str(v.to_string().as_str()),
+            Value::Decimal(v) => serialize_tagged(serializer, DECIMAL_TOKEN, &v.to_string()),
+            Value::Date(v) => v.serialize(serializer),
+            Value::Time(v) => v.serialize(serializer),
+            Value::DateTime(v) => v.serialize(serializer),
+            Value::YearMonth(v) => v.serialize(serializer),
             Value::String(v) => serializer.serialize_str(v),
             Value::Binary(v) => serializer.serialize_bytes(v),
             Value::Array(v) => serializer.collect_seq(v),
@@ -0,0 +1,554 @@
+use std::collections::BTreeMap;
+
+use serde::{ser::Impossible, Serialize};
+
+use crate::{
+    date::{Date, DateTime, Time, YearMonth},
+    error::{AppError, ERR_SERIALIZE},
+    Result, OK,
+};
+
+use super::{
+    main::Value,
+    token::{DATETIME_TOKEN, DATE_TOKEN, DECIMAL_TOKEN, TIME_TOKEN, YEARMONTH_TOKEN},
+};
+
+/// 将任意实现了Serialize的对象转换为内置Value
+///
+/// date模块中的日期类型会转换为对应的Value日期类型，不会转换为字符串
+///
+/// Value::Decimal可原样转换，其它结构中的Decimal字段按其自身的序列化方式转换为字符串
+pub fn to_value<T>(value: &T) -> Result<Value>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(ValueSerializer)
+}
+
+/// 输出结果为内置Value的Serializer
+struct ValueSerializer;
+
+/// 检查是否为内置Value的Serializer
+///
+/// Serializer通常带有生命周期参数，无法使用std::any::TypeId，因此采用忽略生命周期的typeid进行比较
+pub(super) fn is_value_serializer<S: serde::Serializer>() -> bool {
+    typeid::of::<S>() == typeid::of::<ValueSerializer>()
+}
+
+impl serde::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = AppError;
+
+    type SerializeSeq = SerializeVec;
+    type SerializeTuple = SerializeVec;
+    type SerializeTupleStruct = SerializeVec;
+    type SerializeTupleVariant = SerializeTupleVariant;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeStructVariant;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        OK(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value> {
+        OK(Value::I32(v as i32))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value> {
+        OK(Value::I32(v as i32))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value> {
+        OK(Value::I32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value> {
+        OK(Value::I64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value> {
+        i64::try_from(v).map(Value::I64).map_err(|e| {
+            ERR_SERIALIZE
+                .msg_detail(format!("i128数据[{}]超出Value可表示的范围", v).as_str())
+                .cause(e)
+        })
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value> {
+        OK(Value::U32(v as u32))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value> {
+        OK(Value::U32(v as u32))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value> {
+        OK(Value::U32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value> {
+        OK(Value::U64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value> {
+        u64::try_from(v).map(Value::U64).map_err(|e| {
+            ERR_SERIALIZE
+                .msg_detail(format!("u128数据[{}]超出Value可表示的范围", v).as_str())
+                .cause(e)
+        })
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value> {
+        OK(Value::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value> {
+        OK(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        OK(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        OK(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        OK(Value::Binary(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        OK(Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        OK(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        OK(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value> {
+        OK(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        let inner = value.serialize(self)?;
+        match name {
+            DATE_TOKEN => Date::parse_str(inner.as_str()?).map(Value::Date),
+            TIME_TOKEN => Time::parse_str(inner.as_str()?).map(Value::Time),
+            DATETIME_TOKEN => DateTime::parse_str(inner.as_str()?).map(Value::DateTime),
+            YEARMONTH_TOKEN => YearMonth::parse_str(inner.as_str()?).map(Value::YearMonth),
+            DECIMAL_TOKEN => inner.as_decimal().map(Value::Decimal),
+            _ => OK(inner),
+        }
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        OK(Value::Object(BTreeMap::from([(
+            variant.to_string(),
+            to_value(value)?,
+        )])))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeVec> {
+        OK(SerializeVec {
+            vec: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeVec> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeVec> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTupleVariant> {
+        OK(SerializeTupleVariant {
+            name: variant.to_string(),
+            vec: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeMap> {
+        OK(SerializeMap {
+            map: BTreeMap::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeStructVariant> {
+        OK(SerializeStructVariant {
+            name: variant.to_string(),
+            map: BTreeMap::new(),
+        })
+    }
+}
+
+struct SerializeVec {
+    vec: Vec<Value>,
+}
+
+impl serde::ser::SerializeSeq for SerializeVec {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.vec.push(to_value(value)?);
+        OK(())
+    }
+
+    fn end(self) -> Result<Value> {
+        OK(Value::Array(self.vec))
+    }
+}
+
+impl serde::ser::SerializeTuple for SerializeVec {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for SerializeVec {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+struct SerializeTupleVariant {
+    name: String,
+    vec: Vec<Value>,
+}
+
+impl serde::ser::SerializeTupleVariant for SerializeTupleVariant {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.vec.push(to_value(value)?);
+        OK(())
+    }
+
+    fn end(self) -> Result<Value> {
+        OK(Value::Object(BTreeMap::from([(
+            self.name,
+            Value::Array(self.vec),
+        )])))
+    }
+}
+
+struct SerializeMap {
+    map: BTreeMap<String, Value>,
+    next_key: Option<String>,
+}
+
+impl serde::ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.next_key = Some(key.serialize(MapKeySerializer)?);
+        OK(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self.next_key.take() {
+            Some(k) => {
+                self.map.insert(k, to_value(value)?);
+                OK(())
+            }
+            None => Err(ERR_SERIALIZE.msg_detail("Map数据在写入键之前写入了值")),
+        }
+    }
+
+    fn end(self) -> Result<Value> {
+        OK(Value::Object(self.map))
+    }
+}
+
+impl serde::ser::SerializeStruct for SerializeMap {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.map.insert(key.to_string(), to_value(value)?);
+        OK(())
+    }
+
+    fn end(self) -> Result<Value> {
+        OK(Value::Object(self.map))
+    }
+}
+
+struct SerializeStructVariant {
+    name: String,
+    map: BTreeMap<String, Value>,
+}
+
+impl serde::ser::SerializeStructVariant for SerializeStructVariant {
+    type Ok = Value;
+    type Error = AppError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.map.insert(key.to_string(), to_value(value)?);
+        OK(())
+    }
+
+    fn end(self) -> Result<Value> {
+        OK(Value::Object(BTreeMap::from([(
+            self.name,
+            Value::Object(self.map),
+        )])))
+    }
+}
+
+/// Object对象的键只能为字符类型，数值及布尔类型会转换为字符
+struct MapKeySerializer;
+
+fn key_must_be_string(kind: &str) -> AppError {
+    ERR_SERIALIZE.msg_detail(format!("Map数据的键不能为{}类型，需为字符类型", kind).as_str())
+}
+
+impl serde::Serializer for MapKeySerializer {
+    type Ok = String;
+    type Error = AppError;
+
+    type SerializeSeq = Impossible<String, AppError>;
+    type SerializeTuple = Impossible<String, AppError>;
+    type SerializeTupleStruct = Impossible<String, AppError>;
+    type SerializeTupleVariant = Impossible<String, AppError>;
+    type SerializeMap = Impossible<String, AppError>;
+    type SerializeStruct = Impossible<String, AppError>;
+    type SerializeStructVariant = Impossible<String, AppError>;
+
+    fn serialize_bool(self, v: bool) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String> {
+        Err(key_must_be_string("f32"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String> {
+        Err(key_must_be_string("f64"))
+    }
+
+    fn serialize_char(self, v: char) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String> {
+        OK(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String> {
+        Err(key_must_be_string("Binary"))
+    }
+
+    fn serialize_none(self) -> Result<String> {
+        Err(key_must_be_string("Null"))
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<String>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String> {
+        Err(key_must_be_string("Null"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String> {
+        Err(key_must_be_string("Null"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String> {
+        OK(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<String>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(key_must_be_string("枚举"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(key_must_be_string("Array"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(key_must_be_string("Array"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(key_must_be_string("Array"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(key_must_be_string("枚举"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(key_must_be_string("Object"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(key_must_be_string("Object"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(key_must_be_string("枚举"))
+    }
+}
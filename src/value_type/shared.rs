@@ -0,0 +1,315 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::{Arc, OnceLock},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::ERR_DATA, Result, OK};
+
+use super::main::Value;
+
+/// 共享结构的内置Value对象
+///
+/// 每个Array及Object节点均由Arc持有，clone仅增加引用计数，修改时只复制从根节点到被修改节点路径上的节点，
+/// 其余子树仍与原对象共享，适用于需要频繁复制的大型配置及模板上下文
+///
+/// 可通过SharedValue::from及Value::from与内置Value对象互相转换，
+/// 引用计数为1时into_value直接取出数据，不会复制
+///
+/// ```ignore
+/// let config = SharedValue::from(value);
+/// let mut copy = config.clone();
+/// copy.set_by_pointer("/server/port", SharedValue::from(Value::I32(8081)))?;
+/// assert!(config.get("database").unwrap().ptr_eq(copy.get("database").unwrap()));
+/// ```
+#[derive(Clone)]
+pub struct SharedValue(Arc<Shared>);
+
+struct Shared {
+    node: Node,
+    /// Array及Object节点转换后的内置Value对象，仅在调用as_value_ref的节点上生成，修改节点时清除
+    cache: OnceLock<Value>,
+}
+
+impl Clone for Shared {
+    fn clone(&self) -> Self {
+        Shared::new(self.node.clone())
+    }
+}
+
+impl Shared {
+    fn new(node: Node) -> Self {
+        Shared {
+            node,
+            cache: OnceLock::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) enum Node {
+    /// Array及Object以外的数据
+    Scalar(Value),
+    Array(Vec<SharedValue>),
+    Object(BTreeMap<String, SharedValue>),
+}
+
+impl SharedValue {
+    pub fn null() -> Self {
+        SharedValue::from(Value::Null)
+    }
+
+    pub fn array(items: Vec<SharedValue>) -> Self {
+        SharedValue::from_node(Node::Array(items))
+    }
+
+    pub fn object(map: BTreeMap<String, SharedValue>) -> Self {
+        SharedValue::from_node(Node::Object(map))
+    }
+
+    pub(crate) fn from_node(node: Node) -> Self {
+        SharedValue(Arc::new(Shared::new(node)))
+    }
+
+    pub(crate) fn node(&self) -> &Node {
+        &self.0.node
+    }
+
+    /// 获取节点的可变引用，节点被共享时先复制当前节点，子节点仍保持共享
+    pub(crate) fn node_mut(&mut self) -> &mut Node {
+        let shared = Arc::make_mut(&mut self.0);
+        shared.cache.take();
+        &mut shared.node
+    }
+
+    /// 是否与另一个对象共享同一节点
+    pub fn ptr_eq(&self, other: &SharedValue) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self.node(), Node::Array(_))
+    }
+
+    pub fn is_object(&self) -> bool {
+        matches!(self.node(), Node::Object(_))
+    }
+
+    /// Array及Object以外的数据，其余返回None
+    pub fn scalar(&self) -> Option<&Value> {
+        match self.node() {
+            Node::Scalar(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 获取对应的内置Value对象的引用
+    ///
+    /// Array及Object节点首次获取时会生成内置Value对象并缓存在当前节点，修改节点后缓存失效，
+    /// 生成过程中不会在子节点上缓存
+    pub fn as_value_ref(&self) -> &Value {
+        match self.node() {
+            Node::Scalar(v) => v,
+            _ => self.0.cache.get_or_init(|| self.to_value()),
+        }
+    }
+
+    /// Array的元素个数或Object的键值对个数，其余返回0
+    pub fn len(&self) -> usize {
+        match self.node() {
+            Node::Array(arr) => arr.len(),
+            Node::Object(obj) => obj.len(),
+            Node::Scalar(_) => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 获取Object中指定键的数据
+    pub fn get(&self, key: &str) -> Option<&SharedValue> {
+        match self.node() {
+            Node::Object(obj) => obj.get(key),
+            _ => None,
+        }
+    }
+
+    /// 获取Array中指定下标的数据
+    pub fn get_index(&self, index: usize) -> Option<&SharedValue> {
+        match self.node() {
+            Node::Array(arr) => arr.get(index),
+            _ => None,
+        }
+    }
+
+    /// 遍历Object中的键值对，其余返回空
+    pub fn entries(&self) -> impl Iterator<Item = (&String, &SharedValue)> {
+        match self.node() {
+            Node::Object(obj) => Some(obj.iter()),
+            _ => None,
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// 遍历Array中的元素，其余返回空
+    pub fn items(&self) -> impl Iterator<Item = &SharedValue> {
+        match self.node() {
+            Node::Array(arr) => Some(arr.iter()),
+            _ => None,
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// 在Object中写入数据，并返回原有数据
+    pub fn insert(&mut self, key: &str, value: SharedValue) -> Result<Option<SharedValue>> {
+        match self.node_mut() {
+            Node::Object(obj) => OK(obj.insert(key.to_string(), value)),
+            _ => Err(ERR_DATA.msg_detail("不是合法的Value::Object对象")),
+        }
+    }
+
+    /// 在Array尾部追加数据
+    pub fn push(&mut self, value: SharedValue) -> Result<()> {
+        match self.node_mut() {
+            Node::Array(arr) => {
+                arr.push(value);
+                OK(())
+            }
+            _ => Err(ERR_DATA.msg_detail("不是合法的Value::Array对象")),
+        }
+    }
+
+    /// 删除Object中指定键的数据
+    pub fn remove(&mut self, key: &str) -> Result<Option<SharedValue>> {
+        match self.node_mut() {
+            Node::Object(obj) => OK(obj.remove(key)),
+            _ => Err(ERR_DATA.msg_detail("不是合法的Value::Object对象")),
+        }
+    }
+
+    /// 复制为内置Value对象
+    pub fn to_value(&self) -> Value {
+        if let Some(v) = self.0.cache.get() {
+            return v.clone();
+        }
+        match self.node() {
+            Node::Scalar(v) => v.clone(),
+            Node::Array(arr) => Value::Array(arr.iter().map(|x| x.to_value()).collect()),
+            Node::Object(obj) => {
+                Value::Object(obj.iter().map(|(k, v)| (k.clone(), v.to_value())).collect())
+            }
+        }
+    }
+
+    /// 转换为内置Value对象，未被共享的节点直接取出数据
+    pub fn into_value(self) -> Value {
+        match Arc::try_unwrap(self.0) {
+            Ok(shared) => match shared.node {
+                Node::Scalar(v) => v,
+                Node::Array(arr) => Value::Array(arr.into_iter().map(|x| x.into_value()).collect()),
+                Node::Object(obj) => {
+                    Value::Object(obj.into_iter().map(|(k, v)| (k, v.into_value())).collect())
+                }
+            },
+            Err(shared) => SharedValue(shared).to_value(),
+        }
+    }
+}
+
+impl Default for SharedValue {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl From<Value> for SharedValue {
+    fn from(value: Value) -> Self {
+        SharedValue::from_node(match value {
+            Value::Array(arr) => Node::Array(arr.into_iter().map(SharedValue::from).collect()),
+            Value::Object(obj) => Node::Object(
+                obj.into_iter()
+                    .map(|(k, v)| (k, SharedValue::from(v)))
+                    .collect(),
+            ),
+            v => Node::Scalar(v),
+        })
+    }
+}
+
+impl From<SharedValue> for Value {
+    fn from(value: SharedValue) -> Self {
+        value.into_value()
+    }
+}
+
+impl Debug for SharedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.node() {
+            Node::Scalar(v) => v.fmt(f),
+            Node::Array(arr) => f.debug_list().entries(arr).finish(),
+            Node::Object(obj) => f.debug_map().entries(obj).finish(),
+        }
+    }
+}
+
+/// 共享同一节点时直接视为相等，其余按Value的相等规则比较
+impl PartialEq for SharedValue {
+    fn eq(&self, other: &Self) -> bool {
+        if self.ptr_eq(other) {
+            return true;
+        }
+        match (self.node(), other.node()) {
+            (Node::Scalar(a), Node::Scalar(b)) => a == b,
+            (Node::Array(a), Node::Array(b)) => a == b,
+            (Node::Object(a), Node::Object(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SharedValue {}
+
+impl PartialEq<Value> for SharedValue {
+    fn eq(&self, other: &Value) -> bool {
+        match (self.node(), other) {
+            (Node::Scalar(a), b) => a == b,
+            (Node::Array(a), Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x == y)
+            }
+            (Node::Object(a), Value::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Serialize for SharedValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match self.node() {
+            Node::Scalar(v) => v.serialize(serializer),
+            Node::Array(arr) => serializer.collect_seq(arr),
+            Node::Object(obj) => serializer.collect_map(obj),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SharedValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(SharedValue::from)
+    }
+}
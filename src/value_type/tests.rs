@@ -0,0 +1,545 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, str::FromStr};
+
+    use rust_decimal::Decimal;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        bean::{MergeOptions, MergeTrait, PointerMutTrait, PointerTrait},
+        context::ContextTrait,
+        date::{Date, DateTime, YearMonth},
+        from_value, to_value, value, ArrayStyle, CoercionPolicy, CoercionRule, SharedValue, Value,
+        ValueBuilder,
+    };
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Level {
+        Low,
+        High(i32),
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Order {
+        id: u64,
+        name: String,
+        remark: Option<String>,
+        created: DateTime,
+        month: YearMonth,
+        data: Vec<u8>,
+        level: Level,
+        tags: Vec<String>,
+    }
+
+    fn order_value() -> Value {
+        let date = Value::String("2022-10-01 12:30:00".to_string())
+            .as_datetime()
+            .unwrap();
+        Value::Object(BTreeMap::from([
+            ("id".to_string(), Value::I64(1)),
+            ("name".to_string(), Value::String("apple".to_string())),
+            ("remark".to_string(), Value::Null),
+            ("created".to_string(), Value::DateTime(date)),
+            (
+                "month".to_string(),
+                Value::YearMonth(YearMonth::parse_str("2022-10").unwrap()),
+            ),
+            ("data".to_string(), Value::Binary(vec![1, 2, 3])),
+            (
+                "level".to_string(),
+                Value::Object(BTreeMap::from([("High".to_string(), Value::I32(3))])),
+            ),
+            (
+                "tags".to_string(),
+                Value::Array(vec![Value::String("a".to_string())]),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_from_value() {
+        let order: Order = from_value(&order_value()).unwrap();
+        assert_eq!(order.id, 1);
+        assert_eq!(order.name, "apple");
+        assert!(order.remark.is_none());
+        assert_eq!(order.created.to_string(), "2022-10-01 12:30:00");
+        assert_eq!(order.month.to_string(), "2022-10");
+        assert_eq!(order.data, vec![1, 2, 3]);
+        assert_eq!(order.level, Level::High(3));
+        assert_eq!(order.tags, vec!["a".to_string()]);
+
+        let level: Level = from_value(&Value::String("Low".to_string())).unwrap();
+        assert_eq!(level, Level::Low);
+        assert!(from_value::<Order>(&Value::I32(1)).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_value() {
+        let date = Value::Date(Date::parse_str("2022-10-01").unwrap());
+        let value = Value::Object(BTreeMap::from([
+            ("date".to_string(), date),
+            ("bin".to_string(), Value::Binary(vec![7])),
+        ]));
+        let res: Value = from_value(&value).unwrap();
+        let obj = res.as_object().unwrap();
+        assert!(matches!(obj.get("date"), Some(Value::Date(_))));
+        assert!(matches!(obj.get("bin"), Some(Value::Binary(_))));
+
+        let json: Value = serde_json::from_str(r#"{"a": [1, "b", null]}"#).unwrap();
+        let arr = json
+            .as_object()
+            .unwrap()
+            .get("a")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[0].as_i64().unwrap(), 1);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Status {
+        Paid { amount: u32 },
+        Closed,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Invoice {
+        id: u64,
+        date: Date,
+        status: Status,
+        history: Vec<Status>,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_to_value() {
+        let invoice = Invoice {
+            id: 7,
+            date: Date::parse_str("2022-10-01").unwrap(),
+            status: Status::Paid { amount: 10 },
+            history: vec![Status::Closed],
+            note: None,
+        };
+        let value = to_value(&invoice).unwrap();
+        let obj = value.as_object().unwrap();
+        assert!(matches!(obj.get("date"), Some(Value::Date(_))));
+        assert!(matches!(obj.get("note"), Some(Value::Null)));
+        assert_eq!(obj.get("id").unwrap().as_u64().unwrap(), 7);
+
+        let invoice2: Invoice = from_value(&value).unwrap();
+        assert_eq!(invoice2.status, Status::Paid { amount: 10 });
+        assert_eq!(invoice2.history, vec![Status::Closed]);
+        assert_eq!(invoice2.date.to_string(), "2022-10-01");
+
+        let json = serde_json::to_string(&invoice.date).unwrap();
+        assert_eq!(json, "\"2022-10-01\"");
+        let rbs = rbs::to_value(&invoice).unwrap();
+        assert_eq!(rbs["date"], rbs::Value::String("2022-10-01".to_string()));
+        let rbs = rbs::to_value(Value::DateTime(
+            DateTime::parse_str("2022-10-01 12:30:00").unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(rbs, rbs::Value::String("2022-10-01 12:30:00".to_string()));
+
+        let bin = to_value(&Value::Binary(vec![1, 2])).unwrap();
+        assert!(matches!(bin, Value::Binary(_)));
+        let key_err = to_value(&std::collections::HashMap::from([(vec![1], 1)]));
+        assert!(key_err.is_err());
+    }
+
+    #[test]
+    fn test_decimal() {
+        let value = Value::Decimal(Decimal::from_str("12.50").unwrap());
+        assert_eq!(value.as_f64().unwrap(), 12.5);
+        let value = Value::Decimal(Decimal::from_str("0.1").unwrap());
+        assert_eq!(value.as_f64().unwrap(), 0.1);
+        let value = Value::Decimal(Decimal::from_str("12345678901234567890.12").unwrap());
+        assert_eq!(value.as_f64().unwrap_err().name_ref(), "ERR_CAST");
+        assert!(from_value::<f64>(&value).is_err());
+        assert_eq!(
+            from_value::<Decimal>(&value).unwrap().to_string(),
+            "12345678901234567890.12"
+        );
+
+        assert_eq!(
+            Value::I64(-3).as_decimal().unwrap(),
+            Decimal::from_str("-3").unwrap()
+        );
+        assert!(Value::String("1.2.3".to_string()).as_decimal().is_err());
+        assert!(Value::F64(f64::NAN).as_decimal().is_err());
+
+        let res: Value =
+            serde_json::from_str(serde_json::to_string(&value).unwrap().as_str()).unwrap();
+        assert_eq!(res.as_str().unwrap(), "12345678901234567890.12");
+        let res = to_value(&value).unwrap();
+        assert!(matches!(res, Value::Decimal(_)));
+        let res: Value = from_value(&value).unwrap();
+        assert!(matches!(res, Value::Decimal(_)));
+    }
+
+    #[test]
+    fn test_cmp_hash() {
+        let decimal = |s: &str| Value::Decimal(Decimal::from_str(s).unwrap());
+        assert_eq!(Value::I32(1), Value::U64(1));
+        assert_eq!(Value::I64(1), Value::F64(1.0));
+        assert_eq!(Value::F64(1.0), decimal("1.00"));
+        assert_eq!(Value::F64(0.1), decimal("0.1"));
+        assert_eq!(Value::F64(-0.0), Value::I32(0));
+        assert_eq!(Value::F64(f64::NAN), Value::F64(f64::NAN));
+        assert_ne!(Value::F64(0.5), Value::I64(0));
+        assert!(Value::I64(i64::MAX) < Value::U64(u64::MAX));
+        assert!(Value::F64(f64::INFINITY) > Value::U64(u64::MAX));
+        assert!(Value::F64(f64::NAN) > Value::F64(f64::INFINITY));
+        assert!(Value::F64(f64::NEG_INFINITY) < Value::I64(i64::MIN));
+        assert!(decimal("0.3") > Value::F32(0.25));
+        assert!(Value::Null < Value::Bool(false));
+        assert!(Value::Bool(true) < Value::I32(0));
+        assert!(Value::I32(0) < Value::String(String::new()));
+
+        let date = Value::Date(Date::parse_str("2022-10-01").unwrap());
+        let datetime = Value::DateTime(DateTime::parse_str("2022-09-30 23:59:59").unwrap());
+        let month = Value::YearMonth(YearMonth::parse_str("2022-10").unwrap());
+        assert!(datetime < date);
+        assert!(date < month);
+        assert_ne!(date, month);
+        let mut dates = vec![month.clone(), date.clone(), datetime.clone()];
+        dates.sort();
+        assert_eq!(dates, vec![datetime, date, month]);
+
+        let a = Value::Object(BTreeMap::from([
+            ("a".to_string(), Value::I32(1)),
+            (
+                "b".to_string(),
+                Value::Array(vec![Value::U32(2), Value::F64(0.5)]),
+            ),
+        ]));
+        let b = Value::Object(BTreeMap::from([
+            ("a".to_string(), Value::F64(1.0)),
+            (
+                "b".to_string(),
+                Value::Array(vec![Value::I64(2), decimal("0.50")]),
+            ),
+        ]));
+        assert_eq!(a, b);
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_ne!(
+            a.content_hash(),
+            Value::Array(vec![a.clone()]).content_hash()
+        );
+        assert_eq!(Value::I32(1).content_hash(), 0xc8e8442b9b6a95be);
+        assert_eq!(Value::U64(1).content_hash(), 0xc8e8442b9b6a95be);
+
+        let set: std::collections::HashSet<Value> = vec![
+            Value::I32(1),
+            Value::U64(1),
+            Value::F64(1.0),
+            Value::F64(1.5),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_coerce() {
+        let policy = CoercionPolicy::new();
+        let s = |v: &str| Value::String(v.to_string());
+        assert_eq!(s(" 42 ").coerce_i64(&policy).unwrap(), 42);
+        assert_eq!(s("42.0").coerce_i32(&policy).unwrap(), 42);
+        assert_eq!(
+            s("18446744073709551615").coerce_u64(&policy).unwrap(),
+            u64::MAX
+        );
+        assert_eq!(Value::F64(3.0).coerce_i64(&policy).unwrap(), 3);
+        assert_eq!(Value::I64(3).coerce_f64(&policy).unwrap(), 3.0);
+        assert_eq!(s("1.5").coerce_f64(&policy).unwrap(), 1.5);
+        assert!(s("yes").coerce_bool(&policy).unwrap());
+        assert!(!s("Off").coerce_bool(&policy).unwrap());
+        assert_eq!(
+            s("2022-10-01T12:30:00")
+                .coerce_datetime(&policy)
+                .unwrap()
+                .to_string(),
+            "2022-10-01 12:30:00"
+        );
+        assert_eq!(
+            s("2022-10-01")
+                .coerce_datetime(&policy)
+                .unwrap()
+                .to_string(),
+            "2022-10-01 00:00:00"
+        );
+
+        let rule = |e: crate::error::AppError| {
+            e.context_map_ref()
+                .and_then(|x| x.get("rule").cloned())
+                .unwrap()
+        };
+        let err = Value::F64(1.5).coerce_i64(&policy).unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_CAST");
+        assert_eq!(rule(err), s("narrow_float"));
+        let err = s("4x2").coerce_i64(&policy).unwrap_err();
+        assert_eq!(rule(err), s("numeric_string"));
+        let err = s("maybe").coerce_bool(&policy).unwrap_err();
+        assert_eq!(rule(err), s("bool_word"));
+        let err = Value::U64(u64::MAX).coerce_f64(&policy).unwrap_err();
+        assert_eq!(rule(err), s("widen_integer"));
+        let err = s("42")
+            .coerce_i64(&CoercionPolicy::new().rule(CoercionRule::NumericString, false))
+            .unwrap_err();
+        assert_eq!(rule(err), s("numeric_string"));
+        assert!(Value::I64(1).coerce_f64(&CoercionPolicy::strict()).is_err());
+        assert!(s("300").coerce_i32(&policy).is_ok());
+        assert!(s("3000000000").coerce_i32(&policy).is_err());
+
+        let map = BTreeMap::from([
+            ("port".to_string(), s("8080")),
+            ("debug".to_string(), s("on")),
+            ("ratio".to_string(), Value::I32(2)),
+            ("empty".to_string(), Value::Null),
+        ]);
+        assert!(map.get_i64("port").is_err());
+        let mut ctx = map.coerced(CoercionPolicy::new());
+        assert_eq!(ctx.get_i64("port").unwrap(), 8080);
+        assert_eq!(map.get_string("port").unwrap(), "8080");
+        assert!(ctx.get_bool("debug").unwrap());
+        assert_eq!(ctx.get_f64("ratio").unwrap(), 2.0);
+        assert_eq!(ctx.get_opt_i64("empty").unwrap(), None);
+        let err = ctx.get_bool("port").unwrap_err();
+        assert_eq!(err.context_map_ref().unwrap().get("key"), Some(&s("port")));
+        assert!(ctx.insert_i64("port", 1).is_err());
+    }
+
+    #[test]
+    fn test_value_macro_builder() {
+        let date = Date::parse_str("2022-10-01").unwrap();
+        let amount = Decimal::from_str("12.50").unwrap();
+        let key = "dynamic";
+        let value = value!({
+            "date": date,
+            "amount": amount,
+            "data": Value::Binary(vec![1, 2]),
+            "nested": {"list": [1, [true, null], {"a": "b"},], "empty": {}},
+            (key): [],
+            "none": null,
+        });
+        let object = value.as_object().unwrap();
+        assert_eq!(object.len(), 6);
+        assert!(matches!(object.get("date"), Some(Value::Date(_))));
+        assert_eq!(object.get("amount"), Some(&Value::Decimal(amount)));
+        assert_eq!(object.get("data"), Some(&Value::Binary(vec![1, 2])));
+        assert_eq!(object.get("dynamic"), Some(&Value::Array(vec![])));
+        assert_eq!(object.get("none"), Some(&Value::Null));
+        assert_eq!(
+            value.p("/nested/list").unwrap(),
+            &Value::Array(vec![
+                Value::I32(1),
+                Value::Array(vec![Value::Bool(true), Value::Null]),
+                Value::Object(BTreeMap::from([(
+                    "a".to_string(),
+                    Value::String("b".to_string())
+                )])),
+            ])
+        );
+        assert_eq!(value!(null), Value::Null);
+        assert_eq!(value!(1 + 2), Value::I32(3));
+
+        let value = ValueBuilder::object()
+            .string("server.host", "127.0.0.1")
+            .i32("server.port", 8080)
+            .date("release", date)
+            .string("/tags/-", "web")
+            .string("/tags/-", "api")
+            .child("extra", ValueBuilder::array().bool("/-", true))
+            .build()
+            .unwrap();
+        assert_eq!(
+            value,
+            value!({
+                "server": {"host": "127.0.0.1", "port": 8080},
+                "release": date,
+                "tags": ["web", "api"],
+                "extra": [true],
+            })
+        );
+
+        let err = ValueBuilder::object()
+            .string("name", "knife")
+            .i32("name.first", 1)
+            .i32("other", 1)
+            .build()
+            .unwrap_err();
+        assert!(err.msg_detail_ref().is_some());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let value = value!({
+            "server": {"host": "127.0.0.1", "ports": [80, 443]},
+            "users": [{"name": "a", "tags": []}, {"name": "b", "extra": {}}],
+            "debug": null,
+        });
+        let map = value.flatten(".", ArrayStyle::Bracket);
+        let keys: Vec<&str> = map.keys().map(|x| x.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "debug",
+                "server.host",
+                "server.ports[0]",
+                "server.ports[1]",
+                "users[0].name",
+                "users[0].tags",
+                "users[1].extra",
+                "users[1].name",
+            ]
+        );
+        assert_eq!(
+            Value::unflatten(&map, ".", ArrayStyle::Bracket).unwrap(),
+            value
+        );
+
+        let map = value.flatten("__", ArrayStyle::Separator);
+        assert_eq!(map.get("users__1__name"), Some(&value!("b")));
+        assert_eq!(
+            Value::unflatten(&map, "__", ArrayStyle::Separator).unwrap(),
+            value
+        );
+        assert_eq!(
+            value!([1, [2]]).flatten(".", ArrayStyle::Bracket),
+            BTreeMap::from([
+                ("[0]".to_string(), Value::I32(1)),
+                ("[1][0]".to_string(), Value::I32(2)),
+            ])
+        );
+        assert_eq!(
+            value!(1).flatten(".", ArrayStyle::Bracket),
+            BTreeMap::from([(String::new(), Value::I32(1))])
+        );
+
+        let unflatten = |pairs: Vec<(&str, Value)>| {
+            let map = pairs
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect::<BTreeMap<String, Value>>();
+            Value::unflatten(&map, ".", ArrayStyle::Bracket)
+        };
+        assert_eq!(
+            unflatten(vec![("a[2]", value!(1)), ("a[0].b", value!({"c": 2}))]).unwrap(),
+            value!({"a": [{"b": {"c": 2}}, null, 1]})
+        );
+        let key = |e: crate::error::AppError| e.context_map_ref().unwrap().get("key").cloned();
+        let err = unflatten(vec![("a", value!(1)), ("a.b", value!(2))]).unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_DATA");
+        assert_eq!(key(err), Some(value!("a.b")));
+        let err = unflatten(vec![("a[0]", value!(1)), ("a.b", value!(2))]).unwrap_err();
+        assert_eq!(key(err), Some(value!("a[0]")));
+        assert!(unflatten(vec![("a", value!({"b": 1})), ("a.b", value!(2))]).is_err());
+        assert!(unflatten(vec![("a", value!({"b": 1})), ("a.c", value!(2))]).is_ok());
+        assert!(unflatten(vec![("a[x]", value!(1))]).is_err());
+        assert!(unflatten(vec![("a[1000]", value!(1))]).is_err());
+
+        let mut map = BTreeMap::from([
+            ("server.port".to_string(), value!(8080)),
+            ("server".to_string(), value!({"hosts": ["a", "b"]})),
+            ("users[0]".to_string(), value!({"name": "a"})),
+        ]);
+        assert_eq!(map.get_i64("server.port").unwrap(), 8080);
+        assert_eq!(map.get_string("server.hosts[1]").unwrap(), "b");
+        assert_eq!(map.get_string("server.hosts.0").unwrap(), "a");
+        assert_eq!(map.get_string("users[0].name").unwrap(), "a");
+        assert!(map.get_value("server.hosts[2]").unwrap().is_none());
+        map.insert_value("server.debug", value!(true)).unwrap();
+        assert!(map.get_bool("server.debug").unwrap());
+    }
+
+    #[test]
+    fn test_shared_value() {
+        let value = value!({
+            "server": {"host": "127.0.0.1", "ports": [80, 443]},
+            "database": {"url": "postgres://localhost", "pool": 10},
+        });
+        let config = SharedValue::from(value.clone());
+        assert_eq!(config, value);
+        let mut copy = config.clone();
+        assert!(copy.ptr_eq(&config));
+
+        copy.set_by_pointer("/server/ports/-", SharedValue::from(value!(8080)))
+            .unwrap();
+        assert!(!copy.ptr_eq(&config));
+        assert!(copy
+            .get("database")
+            .unwrap()
+            .ptr_eq(config.get("database").unwrap()));
+        assert!(!copy
+            .get("server")
+            .unwrap()
+            .ptr_eq(config.get("server").unwrap()));
+        assert_eq!(config.to_value(), value);
+        assert_eq!(
+            copy.p("/server/ports").unwrap().to_value(),
+            value!([80, 443, 8080])
+        );
+        assert!(copy.remove_by_pointer("/server/none").unwrap().is_none());
+        assert_eq!(
+            copy.remove_by_pointer("/server/host").unwrap().unwrap(),
+            value!("127.0.0.1")
+        );
+        assert!(config.p("/server/host").is_some());
+
+        assert!(config.get_string("database").is_err());
+        assert!(config.get_i64("missing").is_err());
+        let database = config.get("database").unwrap();
+        assert_eq!(database.get_i64("pool").unwrap(), 10);
+        assert_eq!(
+            config.get_value("server").unwrap().unwrap(),
+            value.as_object().unwrap().get("server").unwrap()
+        );
+        // 读取的数据来自子节点的缓存，写时复制后未修改的子节点仍保留缓存
+        let server = config.get_value("server").unwrap().unwrap();
+        assert!(std::ptr::eq(
+            server,
+            config.get("server").unwrap().as_value_ref()
+        ));
+        let mut changed = config.clone();
+        changed.insert_value("debug", value!(true)).unwrap();
+        assert!(std::ptr::eq(
+            changed.get_value("server").unwrap().unwrap(),
+            server
+        ));
+        changed
+            .set_by_pointer("/server/host", SharedValue::from(value!("localhost")))
+            .unwrap();
+        assert_eq!(
+            changed.get_value("server").unwrap().unwrap(),
+            &value!({"host": "localhost", "ports": [80, 443]})
+        );
+
+        let mut merged = config.clone();
+        let res = merged
+            .merge_self(&SharedValue::from(value!({"database": {"pool": 20}})))
+            .unwrap();
+        assert!(res.ptr_eq(&merged));
+        assert!(merged
+            .get("server")
+            .unwrap()
+            .ptr_eq(config.get("server").unwrap()));
+        assert_eq!(merged.p("/database/pool").unwrap(), &value!(20));
+        assert_eq!(config.p("/database/pool").unwrap(), &value!(10));
+        let res = merged
+            .merge_with(
+                &SharedValue::from(value!({"server": {"ports": [1], "host": null}})),
+                &MergeOptions::merge_patch(),
+            )
+            .unwrap();
+        assert_eq!(res.get("server").unwrap(), &value!({"ports": [1]}));
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, serde_json::to_string(&value).unwrap());
+        let res: SharedValue = serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(res, config);
+        assert_eq!(Value::from(config), value);
+    }
+}
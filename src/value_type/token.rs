@@ -0,0 +1,33 @@
+//! 内置Value在序列化/反序列化过程中使用的标记名称
+//!
+//! 日期及Decimal类型由内置Value的Serializer序列化时会以对应名称调用serialize_newtype_struct，
+//! 内置Value的Serializer识别到该名称后可还原为对应的类型，其它Serializer直接输出字符串。
+//! 反序列化为Value时会以VALUE_TOKEN调用deserialize_newtype_struct，
+//! 内置Value的Deserializer识别到该名称后会将日期及Decimal类型以带标记的Map方式传递，从而保留原始类型
+
+use super::serializer::is_value_serializer;
+
+pub(crate) const VALUE_TOKEN: &str = "$__knife_util_private_value";
+pub(crate) const DATE_TOKEN: &str = "$__knife_util_private_date";
+pub(crate) const TIME_TOKEN: &str = "$__knife_util_private_time";
+pub(crate) const DATETIME_TOKEN: &str = "$__knife_util_private_datetime";
+pub(crate) const YEARMONTH_TOKEN: &str = "$__knife_util_private_yearmonth";
+pub(crate) const DECIMAL_TOKEN: &str = "$__knife_util_private_decimal";
+
+/// 序列化带标记的字符串数据，仅内置Value的Serializer会收到标记名称
+///
+/// rbs等格式会将newtype_struct的名称原样输出，因此其它Serializer直接按字符串序列化
+pub(crate) fn serialize_tagged<S>(
+    serializer: S,
+    token: &'static str,
+    text: &str,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    if is_value_serializer::<S>() {
+        serializer.serialize_newtype_struct(token, text)
+    } else {
+        serializer.serialize_str(text)
+    }
+}
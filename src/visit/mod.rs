@@ -0,0 +1,18 @@
+//! 内置Value对象遍历工具类
+//!
+//! 通过ValueVisitor只读遍历，或通过ValueTransformer原地修改内置Value对象，
+//! 均按深度优先的顺序访问所有节点，并提供当前节点的路径，
+//! 可通过返回Visit::SkipChildren跳过子节点、Visit::Stop提前结束，返回异常时立即结束并返回该异常
+//!
+//! 提供以下常用的转换工具：
+//! - `KeyCase`：将Object中的键转换为下划线或驼峰格式
+//! - `PruneNulls`：删除值为Null的节点
+//! - `TrimStrings`：去除字符串首尾的空白字符
+mod model;
+mod tests;
+mod transformers;
+mod walk;
+
+pub use model::{PathSegment, ValuePath, Visit};
+pub use transformers::{KeyCase, PruneNulls, TrimStrings};
+pub use walk::{ValueTransformer, ValueVisitor};
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+use crate::bean::escape_token;
+
+/// 访问节点后的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visit {
+    /// 继续访问子节点
+    Continue,
+    /// 不访问当前节点的子节点，继续访问其余节点
+    SkipChildren,
+    /// 结束遍历
+    Stop,
+}
+
+/// 路径中的一段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// 当前节点相对于根节点的路径
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValuePath {
+    segments: Vec<PathSegment>,
+}
+
+impl ValuePath {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// 节点深度，根节点为0
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// 当前节点在上级Object中的键
+    pub fn key(&self) -> Option<&str> {
+        match self.segments.last() {
+            Some(PathSegment::Key(k)) => Some(k.as_str()),
+            _ => None,
+        }
+    }
+
+    /// 当前节点在上级Array中的下标
+    pub fn index(&self) -> Option<usize> {
+        match self.segments.last() {
+            Some(PathSegment::Index(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// RFC6901格式的路径，根节点为空字符串
+    pub fn pointer(&self) -> String {
+        self.to_string()
+    }
+
+    pub(super) fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    pub(super) fn pop(&mut self) {
+        self.segments.pop();
+    }
+}
+
+impl Display for ValuePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Key(k) => write!(f, "/{}", escape_token(k))?,
+                PathSegment::Index(i) => write!(f, "/{}", i)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ERR_DATA,
+        value,
+        visit::{KeyCase, PruneNulls, TrimStrings, ValuePath, ValueVisitor, Visit},
+        Result, Value, OK,
+    };
+
+    /// 记录访问顺序，遇到键为skip的节点时跳过子节点
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl ValueVisitor for Recorder {
+        fn visit(&mut self, path: &ValuePath, _value: &Value) -> Result<Visit> {
+            self.events.push(format!("+{}", path));
+            if path.key() == Some("skip") {
+                return OK(Visit::SkipChildren);
+            }
+            OK(Visit::Continue)
+        }
+
+        fn leave(&mut self, path: &ValuePath, _value: &Value) -> Result<()> {
+            self.events.push(format!("-{}", path));
+            OK(())
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        let value = value!({"a": [1, {"b/c": 2}], "skip": {"x": 1}});
+        let mut recorder = Recorder { events: vec![] };
+        value.walk(&mut recorder).unwrap();
+        assert_eq!(
+            recorder.events,
+            vec![
+                "+",
+                "+/a",
+                "+/a/0",
+                "-/a/0",
+                "+/a/1",
+                "+/a/1/b~1c",
+                "-/a/1/b~1c",
+                "-/a/1",
+                "-/a",
+                "+/skip",
+                "-/skip",
+                "-",
+            ]
+        );
+
+        let mut count = 0;
+        value
+            .walk(&mut |path: &ValuePath, _: &Value| {
+                count += 1;
+                OK(if path.index() == Some(0) {
+                    Visit::Stop
+                } else {
+                    Visit::Continue
+                })
+            })
+            .unwrap();
+        assert_eq!(count, 3);
+
+        let err = value
+            .walk(&mut |path: &ValuePath, v: &Value| {
+                if path.depth() == 3 && v.as_i64()? == 2 {
+                    return Err(ERR_DATA.msg_detail("数据不正确"));
+                }
+                OK(Visit::Continue)
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.context_map_ref().unwrap().get("pointer"),
+            Some(&value!("/a/1/b~1c"))
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut value = value!({
+            "userName": "  张三 ",
+            "password": "123456",
+            "HTTPProxy": null,
+            "addressList": [{"zipCode": " 200000", "remark": null}, null],
+        });
+        value
+            .transform(&mut |path: &ValuePath, v: &mut Value| {
+                if path.key() == Some("password") {
+                    *v = value!("******");
+                }
+                OK(Visit::Continue)
+            })
+            .unwrap();
+        value.transform(&mut KeyCase::Snake).unwrap();
+        value.transform(&mut TrimStrings).unwrap();
+        value.transform(&mut PruneNulls::new()).unwrap();
+        assert_eq!(
+            value,
+            value!({
+                "user_name": "张三",
+                "password": "******",
+                "address_list": [{"zip_code": "200000"}, null],
+            })
+        );
+        value
+            .transform(&mut PruneNulls::new().arrays(true))
+            .unwrap();
+        value.transform(&mut KeyCase::Camel).unwrap();
+        assert_eq!(
+            value,
+            value!({
+                "userName": "张三",
+                "password": "******",
+                "addressList": [{"zipCode": "200000"}],
+            })
+        );
+
+        let mut value = value!({"a": {"userName": 1, "user_name": 2}});
+        let err = value.transform(&mut KeyCase::Snake).unwrap_err();
+        assert_eq!(err.name_ref(), "ERR_DATA");
+        assert_eq!(
+            err.context_map_ref().unwrap().get("pointer"),
+            Some(&value!("/a"))
+        );
+        assert_eq!(value, value!({"a": {"userName": 1, "user_name": 2}}));
+    }
+}
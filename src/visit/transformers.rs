@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+
+use crate::{error::ERR_DATA, types::StringExt, Result, Value, OK};
+
+use super::{
+    model::{ValuePath, Visit},
+    walk::ValueTransformer,
+};
+
+/// 将Object中的键转换为指定格式
+///
+/// 转换后存在重复的键时返回ERR_DATA异常，此时该Object保持不变
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCase {
+    /// 下划线格式，如：user_name
+    Snake,
+    /// 小驼峰格式，如：userName
+    Camel,
+}
+
+impl KeyCase {
+    pub fn convert(&self, key: &str) -> String {
+        match self {
+            KeyCase::Snake => key.to_snake_case(),
+            KeyCase::Camel => key.to_camel_case(),
+        }
+    }
+}
+
+impl ValueTransformer for KeyCase {
+    fn transform(&mut self, _path: &ValuePath, value: &mut Value) -> Result<Visit> {
+        if let Value::Object(obj) = value {
+            let mut keys = BTreeSet::new();
+            for k in obj.keys() {
+                let key = self.convert(k);
+                if !keys.insert(key.clone()) {
+                    return Err(ERR_DATA
+                        .msg_detail(format!("键[{}]转换后的[{}]与其他键重复", k, key).as_str())
+                        .context_value("key".to_string(), Value::String(k.clone())));
+                }
+            }
+            *obj = std::mem::take(obj)
+                .into_iter()
+                .map(|(k, v)| (self.convert(&k), v))
+                .collect();
+        }
+        OK(Visit::Continue)
+    }
+}
+
+/// 删除Object中值为Null的节点，可选择同时删除Array中的Null元素
+#[derive(Debug, Clone, Default)]
+pub struct PruneNulls {
+    arrays: bool,
+}
+
+impl PruneNulls {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 是否删除Array中的Null元素，删除后其余元素的下标会发生变化
+    pub fn arrays(mut self, enabled: bool) -> Self {
+        self.arrays = enabled;
+        self
+    }
+}
+
+impl ValueTransformer for PruneNulls {
+    fn transform(&mut self, _path: &ValuePath, value: &mut Value) -> Result<Visit> {
+        match value {
+            Value::Object(obj) => obj.retain(|_, v| !matches!(v, Value::Null)),
+            Value::Array(arr) if self.arrays => arr.retain(|v| !matches!(v, Value::Null)),
+            _ => {}
+        }
+        OK(Visit::Continue)
+    }
+}
+
+/// 去除字符串首尾的空白字符
+#[derive(Debug, Clone, Default)]
+pub struct TrimStrings;
+
+impl ValueTransformer for TrimStrings {
+    fn transform(&mut self, _path: &ValuePath, value: &mut Value) -> Result<Visit> {
+        if let Value::String(s) = value {
+            let trimmed = s.trim();
+            if trimmed.len() != s.len() {
+                *s = trimmed.to_string();
+            }
+        }
+        OK(Visit::Continue)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{error::AppError, Result, Value, OK};
+
+use super::model::{PathSegment, ValuePath, Visit};
+
+/// 只读遍历内置Value对象
+///
+/// 可直接使用`FnMut(&ValuePath, &Value) -> Result<Visit>`类型的闭包
+pub trait ValueVisitor {
+    /// 访问节点，在访问子节点之前调用
+    fn visit(&mut self, path: &ValuePath, value: &Value) -> Result<Visit>;
+
+    /// 子节点访问完成后调用，返回Visit::SkipChildren时同样会调用，返回Visit::Stop时不再调用
+    fn leave(&mut self, _path: &ValuePath, _value: &Value) -> Result<()> {
+        OK(())
+    }
+}
+
+impl<F> ValueVisitor for F
+where
+    F: FnMut(&ValuePath, &Value) -> Result<Visit>,
+{
+    fn visit(&mut self, path: &ValuePath, value: &Value) -> Result<Visit> {
+        self(path, value)
+    }
+}
+
+/// 遍历并原地修改内置Value对象
+///
+/// 可直接使用`FnMut(&ValuePath, &mut Value) -> Result<Visit>`类型的闭包
+pub trait ValueTransformer {
+    /// 修改节点，在访问子节点之前调用，之后按修改后的数据访问子节点
+    fn transform(&mut self, path: &ValuePath, value: &mut Value) -> Result<Visit>;
+
+    /// 子节点修改完成后调用，返回Visit::SkipChildren时同样会调用，返回Visit::Stop时不再调用
+    fn transform_after(&mut self, _path: &ValuePath, _value: &mut Value) -> Result<()> {
+        OK(())
+    }
+}
+
+impl<F> ValueTransformer for F
+where
+    F: FnMut(&ValuePath, &mut Value) -> Result<Visit>,
+{
+    fn transform(&mut self, path: &ValuePath, value: &mut Value) -> Result<Visit> {
+        self(path, value)
+    }
+}
+
+impl Value {
+    /// 按深度优先的顺序遍历所有节点，Object中的节点按键的顺序访问
+    ///
+    /// 返回Visit::Stop时结束遍历并返回OK，返回异常时结束遍历，异常上下文中的pointer为对应节点的路径
+    pub fn walk<V: ValueVisitor + ?Sized>(&self, visitor: &mut V) -> Result<()> {
+        walk_node(self, &mut ValuePath::default(), visitor).map(|_| ())
+    }
+
+    /// 按深度优先的顺序遍历并修改所有节点，Object中的节点按键的顺序访问
+    ///
+    /// 返回Visit::Stop时结束遍历并返回OK，返回异常时结束遍历，异常上下文中的pointer为对应节点的路径，
+    /// 结束遍历前已完成的修改会保留
+    pub fn transform<T: ValueTransformer + ?Sized>(&mut self, transformer: &mut T) -> Result<()> {
+        transform_node(self, &mut ValuePath::default(), transformer).map(|_| ())
+    }
+}
+
+/// 返回false表示已结束遍历
+fn walk_node<V: ValueVisitor + ?Sized>(
+    value: &Value,
+    path: &mut ValuePath,
+    visitor: &mut V,
+) -> Result<bool> {
+    match visitor
+        .visit(path, value)
+        .map_err(|e| with_pointer(e, path))?
+    {
+        Visit::Stop => return OK(false),
+        Visit::SkipChildren => {}
+        Visit::Continue => {
+            let children: Box<dyn Iterator<Item = (PathSegment, &Value)>> = match value {
+                Value::Array(arr) => Box::new(
+                    arr.iter()
+                        .enumerate()
+                        .map(|(i, v)| (PathSegment::Index(i), v)),
+                ),
+                Value::Object(obj) => {
+                    Box::new(obj.iter().map(|(k, v)| (PathSegment::Key(k.clone()), v)))
+                }
+                _ => Box::new(std::iter::empty()),
+            };
+            for (segment, v) in children {
+                path.push(segment);
+                let res = walk_node(v, path, visitor);
+                path.pop();
+                if !res? {
+                    return OK(false);
+                }
+            }
+        }
+    }
+    visitor
+        .leave(path, value)
+        .map_err(|e| with_pointer(e, path))?;
+    OK(true)
+}
+
+/// 返回false表示已结束遍历
+fn transform_node<T: ValueTransformer + ?Sized>(
+    value: &mut Value,
+    path: &mut ValuePath,
+    transformer: &mut T,
+) -> Result<bool> {
+    match transformer
+        .transform(path, value)
+        .map_err(|e| with_pointer(e, path))?
+    {
+        Visit::Stop => return OK(false),
+        Visit::SkipChildren => {}
+        Visit::Continue => {
+            let children: Box<dyn Iterator<Item = (PathSegment, &mut Value)>> = match value {
+                Value::Array(arr) => Box::new(
+                    arr.iter_mut()
+                        .enumerate()
+                        .map(|(i, v)| (PathSegment::Index(i), v)),
+                ),
+                Value::Object(obj) => Box::new(
+                    obj.iter_mut()
+                        .map(|(k, v)| (PathSegment::Key(k.clone()), v)),
+                ),
+                _ => Box::new(std::iter::empty()),
+            };
+            for (segment, v) in children {
+                path.push(segment);
+                let res = transform_node(v, path, transformer);
+                path.pop();
+                if !res? {
+                    return OK(false);
+                }
+            }
+        }
+    }
+    transformer
+        .transform_after(path, value)
+        .map_err(|e| with_pointer(e, path))?;
+    OK(true)
+}
+
+fn with_pointer(e: AppError, path: &ValuePath) -> AppError {
+    e.context_value("pointer".to_string(), Value::String(path.pointer()))
+}